@@ -1,5 +1,6 @@
 //! A simple enumeration for the 4 cardinal directions.
 
+use core::iter::FusedIterator;
 use core::ops::{Add, Mul, Neg, Sub};
 
 use crate::rotation::Rotation;
@@ -184,6 +185,56 @@ impl Direction {
         }
     }
 
+    /// Reflect this direction across a horizontal axis, as though it bounced
+    /// off a horizontal wall. `Up` and `Down` are swapped, while `Left` and
+    /// `Right` are unchanged.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// assert_eq!(Up.reflect_vertical(), Down);
+    /// assert_eq!(Down.reflect_vertical(), Up);
+    /// assert_eq!(Left.reflect_vertical(), Left);
+    /// assert_eq!(Right.reflect_vertical(), Right);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reflect_vertical(self) -> Direction {
+        match self {
+            Up => Down,
+            Down => Up,
+            Left => Left,
+            Right => Right,
+        }
+    }
+
+    /// Reflect this direction across a vertical axis, as though it bounced
+    /// off a vertical wall. `Left` and `Right` are swapped, while `Up` and
+    /// `Down` are unchanged.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// assert_eq!(Up.reflect_horizontal(), Up);
+    /// assert_eq!(Down.reflect_horizontal(), Down);
+    /// assert_eq!(Left.reflect_horizontal(), Right);
+    /// assert_eq!(Right.reflect_horizontal(), Left);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reflect_horizontal(self) -> Direction {
+        match self {
+            Up => Up,
+            Down => Down,
+            Left => Right,
+            Right => Left,
+        }
+    }
+
     /// Rotate this direction clockwise
     ///
     /// # Example:
@@ -230,6 +281,84 @@ impl Direction {
         }
     }
 
+    /// Rotate this direction clockwise by `n` 90° turns. `n` may be negative,
+    /// in which case the direction is rotated anticlockwise. This is computed
+    /// directly (via `n.rem_euclid(4)`) rather than by repeated turning.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// assert_eq!(Up.clockwise_steps(1), Right);
+    /// assert_eq!(Up.clockwise_steps(2), Down);
+    /// assert_eq!(Up.clockwise_steps(-1), Left);
+    /// assert_eq!(Left.clockwise_steps(9), Up);
+    /// assert_eq!(Down.clockwise_steps(-6), Up);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn clockwise_steps(self, n: i32) -> Direction {
+        self.rotate(Rotation::Clockwise * n)
+    }
+
+    /// Rotate this direction anticlockwise by `n` 90° turns. `n` may be
+    /// negative, in which case the direction is rotated clockwise.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// assert_eq!(Up.anticlockwise_steps(1), Left);
+    /// assert_eq!(Up.anticlockwise_steps(-1), Right);
+    /// assert_eq!(Right.anticlockwise_steps(7), Down);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn anticlockwise_steps(self, n: i32) -> Direction {
+        self.rotate(Rotation::Anticlockwise * n)
+    }
+
+    /// Get an iterator over all four directions, in clockwise order, starting
+    /// with this direction. This is useful for trying each direction in turn
+    /// relative to a current heading, such as in a wall-following robot.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// let directions: Vec<Direction> = Left.clockwise_cycle().collect();
+    /// assert_eq!(directions, [Left, Up, Right, Down]);
+    /// ```
+    #[inline]
+    pub fn clockwise_cycle(
+        self,
+    ) -> impl DoubleEndedIterator<Item = Direction> + ExactSizeIterator + FusedIterator + Clone
+    {
+        (0..4).map(move |n| self.clockwise_steps(n))
+    }
+
+    /// Get an iterator over all four directions, in anticlockwise order,
+    /// starting with this direction.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::direction::*;
+    ///
+    /// let directions: Vec<Direction> = Left.anticlockwise_cycle().collect();
+    /// assert_eq!(directions, [Left, Down, Right, Up]);
+    /// ```
+    #[inline]
+    pub fn anticlockwise_cycle(
+        self,
+    ) -> impl DoubleEndedIterator<Item = Direction> + ExactSizeIterator + FusedIterator + Clone
+    {
+        (0..4).map(move |n| self.anticlockwise_steps(n))
+    }
+
     /// Rotate this direction by the given `rotation`.
     ///
     /// # Example
@@ -471,6 +600,109 @@ impl VectorLike for Direction {
     fn direction(&self) -> Option<Direction> {
         Some(*self)
     }
+
+    #[inline]
+    fn signum(&self) -> Vector {
+        self.unit_vec()
+    }
+}
+
+/// With the `serde` feature, directions serialize as their name (`"Up"`,
+/// `"Right"`, `"Down"`, or `"Left"`) in human-readable formats like JSON, and
+/// as a compact `u8` (in the order `Up`, `Right`, `Down`, `Left`) in binary
+/// formats. In human-readable formats, directions deserialize from any of the
+/// case-insensitive names accepted by [`Direction::from_name`].
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::fmt;
+
+    use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    use super::*;
+
+    const NAMES: &[&str] = &["Up", "Right", "Down", "Left"];
+
+    impl Serialize for Direction {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.serialize_str(NAMES[*self as usize])
+            } else {
+                serializer.serialize_u8(*self as u8)
+            }
+        }
+    }
+
+    struct DirectionVisitor;
+
+    impl<'de> Visitor<'de> for DirectionVisitor {
+        type Value = Direction;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a direction name or index")
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<Direction, E> {
+            Direction::from_name(name).ok_or_else(|| E::unknown_variant(name, NAMES))
+        }
+
+        fn visit_u64<E: de::Error>(self, index: u64) -> Result<Direction, E> {
+            match index {
+                0 => Ok(Up),
+                1 => Ok(Right),
+                2 => Ok(Down),
+                3 => Ok(Left),
+                _ => Err(E::invalid_value(
+                    Unexpected::Unsigned(index),
+                    &"a direction index from 0 to 3",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Direction {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Direction, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(DirectionVisitor)
+            } else {
+                deserializer.deserialize_u8(DirectionVisitor)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        use super::*;
+
+        #[test]
+        fn test_json_round_trip() {
+            for &(direction, json) in &[
+                (Up, "\"Up\""),
+                (Right, "\"Right\""),
+                (Down, "\"Down\""),
+                (Left, "\"Left\""),
+            ] {
+                assert_eq!(serde_json::to_string(&direction).unwrap(), json);
+                assert_eq!(serde_json::from_str::<Direction>(json).unwrap(), direction);
+            }
+        }
+
+        #[test]
+        fn test_json_names() {
+            assert_eq!(serde_json::from_str::<Direction>("\"north\"").unwrap(), Up);
+            assert_eq!(serde_json::from_str::<Direction>("\"W\"").unwrap(), Left);
+            assert!(serde_json::from_str::<Direction>("\"sideways\"").is_err());
+        }
+
+        #[test]
+        fn test_compact() {
+            for &(direction, index) in &[(Up, 0), (Right, 1), (Down, 2), (Left, 3)] {
+                assert_tokens(&direction.compact(), &[Token::U8(index)]);
+            }
+        }
+    }
 }
 
 #[test]
@@ -502,6 +734,48 @@ fn test_from_str() {
     assert_eq!(Direction::from_name("foo"), None);
 }
 
+#[test]
+fn test_clockwise_steps() {
+    for &direction in &EACH_DIRECTION {
+        assert_eq!(direction.clockwise_steps(0), direction);
+        assert_eq!(direction.clockwise_steps(1), direction.clockwise());
+        assert_eq!(direction.clockwise_steps(2), direction.reverse());
+        assert_eq!(direction.clockwise_steps(3), direction.anticlockwise());
+        assert_eq!(direction.clockwise_steps(4), direction);
+        assert_eq!(direction.clockwise_steps(-1), direction.anticlockwise());
+        assert_eq!(direction.clockwise_steps(i32::MIN), direction);
+        assert_eq!(
+            direction.clockwise_steps(i32::MAX),
+            direction.anticlockwise()
+        );
+
+        assert_eq!(direction.anticlockwise_steps(0), direction);
+        assert_eq!(direction.anticlockwise_steps(1), direction.anticlockwise());
+        assert_eq!(direction.anticlockwise_steps(2), direction.reverse());
+        assert_eq!(direction.anticlockwise_steps(3), direction.clockwise());
+        assert_eq!(direction.anticlockwise_steps(4), direction);
+        assert_eq!(direction.anticlockwise_steps(-1), direction.clockwise());
+    }
+}
+
+#[test]
+fn test_direction_cycles() {
+    assert!(Up
+        .clockwise_cycle()
+        .eq([Up, Right, Down, Left].iter().copied()));
+    assert!(Up
+        .anticlockwise_cycle()
+        .eq([Up, Left, Down, Right].iter().copied()));
+
+    for &direction in &EACH_DIRECTION {
+        assert_eq!(direction.clockwise_cycle().len(), 4);
+        assert!(direction
+            .clockwise_cycle()
+            .rev()
+            .eq(direction.anticlockwise().anticlockwise_cycle()));
+    }
+}
+
 #[cfg(test)]
 mod test_vectorlike {
     use crate::direction::EACH_DIRECTION;
@@ -547,6 +821,7 @@ mod test_vectorlike {
         test_vectorlike_method! {reverse}
         test_vectorlike_method! {transpose}
         test_vectorlike_method! {direction}
+        test_vectorlike_method! {signum}
     }
 }
 
@@ -569,3 +844,58 @@ mod test_vectorlike {
 /// assert_eq!(adjacent.len(), 4);
 /// ```
 pub static EACH_DIRECTION: [Direction; 4] = [Up, Right, Down, Left];
+
+/// Get the net displacement of a sequence of directions; that is, the sum of
+/// a unit vector in each direction. This is useful for evaluating movement
+/// traces, such as turtle paths. It's equivalent to summing the directions
+/// into a [`Vector`] directly.
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::direction::net_displacement;
+///
+/// assert_eq!(net_displacement(vec![Up, Up, Right, Down]), (-1, 1));
+/// assert_eq!(net_displacement(EACH_DIRECTION.iter().copied()), (0, 0));
+/// assert_eq!(net_displacement(None), (0, 0));
+/// ```
+#[inline]
+#[must_use]
+pub fn net_displacement(directions: impl IntoIterator<Item = Direction>) -> Vector {
+    directions.into_iter().sum()
+}
+
+#[test]
+fn test_direction_sum() {
+    let trace = [Up, Up, Right, Down];
+    let expected = Vector::new(-1, 1);
+
+    assert_eq!(trace.iter().copied().sum::<Vector>(), expected);
+    assert_eq!(trace.iter().sum::<Vector>(), expected);
+    assert_eq!(net_displacement(trace.iter().copied()), expected);
+}
+
+#[test]
+fn test_direction_reflect() {
+    use crate::vector::VectorLike;
+
+    assert_eq!(Up.reflect_vertical(), Down);
+    assert_eq!(Up.reflect_horizontal(), Up);
+
+    for &direction in &EACH_DIRECTION {
+        assert_eq!(
+            direction.reflect_vertical().as_vector(),
+            direction.as_vector().reflect_across_row()
+        );
+        assert_eq!(
+            direction.reflect_horizontal().as_vector(),
+            direction.as_vector().reflect_across_column()
+        );
+        assert_eq!(direction.reflect_vertical().reflect_vertical(), direction);
+        assert_eq!(
+            direction.reflect_vertical().reflect_horizontal(),
+            direction.reverse()
+        );
+    }
+}
@@ -1,10 +1,14 @@
 use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
 
 use crate::location::{Column, Component as LocComponent, Location, LocationLike, Row};
 use crate::range::{
     ColumnRange, ColumnRangeError, ComponentRange, RangeError, RowRange, RowRangeError,
 };
-use crate::vector::{Columns, Component as VecComponent, Rows, Vector, VectorLike};
+use crate::vector::{
+    Columns, Component as VecComponent, Rows, Vector, VectorLike, ORTHOGONAL_ADJACENCIES,
+    TOUCHING_ADJACENCIES,
+};
 
 /// Grid trait implementing grid sizes and boundary checking.
 ///
@@ -183,6 +187,272 @@ pub trait GridBounds {
     fn location_in_bounds(&self, location: impl LocationLike) -> bool {
         self.check_location(location).is_ok()
     }
+
+    /// Get the in-bounds location nearest to `location`, by clamping its row
+    /// and column into the grid's row and column ranges. Locations that are
+    /// already in bounds are returned unchanged. This is the "clamp to edge"
+    /// addressing mode used in texture sampling.
+    ///
+    /// If the grid is empty, it has no in-bounds locations, and the clamped
+    /// location (which is computed with [`ComponentRange::clamp`]) is out of
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid: VecGrid<i32> = VecGrid::new((Rows(3), Columns(4))).unwrap();
+    ///
+    /// assert_eq!(grid.clamp_location((1, 2)), (1, 2));
+    /// assert_eq!(grid.clamp_location((-10, 2)), (0, 2));
+    /// assert_eq!(grid.clamp_location((1, 10)), (1, 3));
+    /// assert_eq!(grid.clamp_location((5, -5)), (2, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn clamp_location(&self, location: impl LocationLike) -> Location {
+        Location {
+            row: self.row_range().clamp(location.row()),
+            column: self.column_range().clamp(location.column()),
+        }
+    }
+
+    /// Get an iterator over the in-bounds locations orthogonally adjacent to
+    /// `location`: the locations directly above, below, left, and right of it,
+    /// skipping any that are out of bounds. Locations on the edge or corner of
+    /// the grid therefore have fewer neighbors. This is the core step of most
+    /// flood-fill and breadth-first search algorithms. The order of the
+    /// neighbors is unspecified and should not be relied upon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid: VecGrid<()> = VecGrid::new((Rows(3), Columns(4))).unwrap();
+    ///
+    /// let neighbors: Vec<Location> = grid.neighbors((1, 1)).collect();
+    /// assert_eq!(neighbors.len(), 4);
+    /// assert!(neighbors.contains(&Location::new(0, 1)));
+    /// assert!(neighbors.contains(&Location::new(1, 2)));
+    /// assert!(neighbors.contains(&Location::new(2, 1)));
+    /// assert!(neighbors.contains(&Location::new(1, 0)));
+    ///
+    /// let corner: Vec<Location> = grid.neighbors((0, 3)).collect();
+    /// assert_eq!(corner.len(), 2);
+    /// assert!(corner.contains(&Location::new(1, 3)));
+    /// assert!(corner.contains(&Location::new(0, 2)));
+    ///
+    /// assert_eq!(grid.neighbors((5, 5)).count(), 0);
+    /// ```
+    #[inline]
+    fn neighbors(&self, location: impl LocationLike) -> BoundedNeighbors<'_, Self> {
+        BoundedNeighbors::new(self, location.as_location(), &ORTHOGONAL_ADJACENCIES)
+    }
+
+    /// Get an iterator over the in-bounds locations that touch `location`,
+    /// orthogonally or diagonally, skipping any that are out of bounds. This
+    /// is the 8-way version of [`neighbors`][GridBounds::neighbors]. The order
+    /// of the neighbors is unspecified and should not be relied upon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid: VecGrid<()> = VecGrid::new((Rows(3), Columns(4))).unwrap();
+    ///
+    /// assert_eq!(grid.touching_neighbors_in_bounds((1, 1)).count(), 8);
+    /// assert_eq!(grid.touching_neighbors_in_bounds((1, 0)).count(), 5);
+    ///
+    /// let corner: Vec<Location> = grid.touching_neighbors_in_bounds((0, 0)).collect();
+    /// assert_eq!(corner.len(), 3);
+    /// assert!(corner.contains(&Location::new(0, 1)));
+    /// assert!(corner.contains(&Location::new(1, 1)));
+    /// assert!(corner.contains(&Location::new(1, 0)));
+    /// ```
+    #[inline]
+    fn touching_neighbors_in_bounds(
+        &self,
+        location: impl LocationLike,
+    ) -> BoundedNeighbors<'_, Self> {
+        BoundedNeighbors::new(self, location.as_location(), &TOUCHING_ADJACENCIES)
+    }
+
+    /// Get the overlapping region of the bounds of this grid and some other
+    /// grid, as a `(root, dimensions)` pair. The root of the overlap is the
+    /// component-wise maximum of the two roots, and the outer bound is the
+    /// component-wise minimum of the two outer bounds.
+    ///
+    /// If the bounds are disjoint (including if they only touch along an
+    /// edge), the returned dimensions will have zero rows and/or columns. In
+    /// this case the root is still the component-wise maximum of the two roots,
+    /// but it isn't necessarily inside either grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid1: VecGrid<()> = VecGrid::new((Rows(4), Columns(4))).unwrap();
+    /// let grid2: VecGrid<()> = VecGrid::new((Rows(3), Columns(6))).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid1.intersect_bounds(&grid2),
+    ///     (Location::new(0, 0), Vector::new(3, 4)),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    fn intersect_bounds(&self, other: &impl GridBounds) -> (Location, Vector) {
+        let root = self.root();
+        let other_root = other.root();
+        let outer = self.outer_bound();
+        let other_outer = other.outer_bound();
+
+        let root = Location {
+            row: root.row.max(other_root.row),
+            column: root.column.max(other_root.column),
+        };
+
+        let outer = Location {
+            row: outer.row.min(other_outer.row),
+            column: outer.column.min(other_outer.column),
+        };
+
+        let dimensions = outer - root;
+
+        (
+            root,
+            Vector {
+                rows: dimensions.rows.max(Rows(0)),
+                columns: dimensions.columns.max(Columns(0)),
+            },
+        )
+    }
+
+    /// Get the smallest region that encloses the bounds of both this grid
+    /// and some other grid, as a `(root, dimensions)` pair. The root of the
+    /// union is the component-wise minimum of the two roots, and the outer
+    /// bound is the component-wise maximum of the two outer bounds. This is
+    /// the complement of [`intersect_bounds`][GridBounds::intersect_bounds],
+    /// and is useful for sizing a canvas that holds both grids.
+    ///
+    /// Note that the union always encloses both roots, even if one of the
+    /// grids is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::SparseGrid;
+    ///
+    /// let grid1: SparseGrid<()> = SparseGrid::new_rooted((0, 0), (Rows(3), Columns(3)));
+    /// let grid2: SparseGrid<()> = SparseGrid::new_rooted((2, -4), (Rows(2), Columns(2)));
+    ///
+    /// assert_eq!(
+    ///     grid1.union_bounds(&grid2),
+    ///     (Location::new(0, -4), Vector::new(4, 7)),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    fn union_bounds(&self, other: &impl GridBounds) -> (Location, Vector) {
+        let root = self.root();
+        let other_root = other.root();
+        let outer = self.outer_bound();
+        let other_outer = other.outer_bound();
+
+        let root = Location {
+            row: root.row.min(other_root.row),
+            column: root.column.min(other_root.column),
+        };
+
+        let outer = Location {
+            row: outer.row.max(other_outer.row),
+            column: outer.column.max(other_outer.column),
+        };
+
+        (root, outer - root)
+    }
+
+    /// Convert an absolute location into an offset relative to the root of
+    /// this grid. This is the inverse of
+    /// [`from_relative`][GridBounds::from_relative], and is useful for
+    /// interfacing with zero-based code, such as flat storage indexes. The
+    /// location doesn't need to be in bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// struct Bounds;
+    ///
+    /// impl GridBounds for Bounds {
+    ///     fn root(&self) -> Location {
+    ///         Location::new(-2, 5)
+    ///     }
+    ///
+    ///     fn dimensions(&self) -> Vector {
+    ///         Vector::new(3, 3)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Bounds.to_relative((-2, 5)), Vector::new(0, 0));
+    /// assert_eq!(Bounds.to_relative((0, 6)), Vector::new(2, 1));
+    /// assert_eq!(Bounds.to_relative((0, 0)), Vector::new(2, -5));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_relative(&self, location: impl LocationLike) -> Vector {
+        location.as_location() - self.root()
+    }
+
+    /// Convert an offset relative to the root of this grid into an absolute
+    /// location. This is the inverse of
+    /// [`to_relative`][GridBounds::to_relative]. The resulting location
+    /// doesn't need to be in bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// struct Bounds;
+    ///
+    /// impl GridBounds for Bounds {
+    ///     fn root(&self) -> Location {
+    ///         Location::new(-2, 5)
+    ///     }
+    ///
+    ///     fn dimensions(&self) -> Vector {
+    ///         Vector::new(3, 3)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Bounds.from_relative((0, 0)), Location::new(-2, 5));
+    /// assert_eq!(Bounds.from_relative((2, 1)), Location::new(0, 6));
+    ///
+    /// let location = Location::new(1, 7);
+    /// assert_eq!(Bounds.from_relative(Bounds.to_relative(location)), location);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_relative(&self, offset: impl VectorLike) -> Location {
+        self.root() + offset
+    }
 }
 
 impl<G: GridBounds> GridBounds for &G {
@@ -209,6 +479,92 @@ impl<G: GridBounds> GridBounds for &mut G {
     }
 }
 
+/// An iterator over the in-bounds neighbors of a location. See
+/// [`neighbors`][GridBounds::neighbors] and
+/// [`touching_neighbors_in_bounds`][GridBounds::touching_neighbors_in_bounds]
+/// for details.
+#[derive(Debug)]
+pub struct BoundedNeighbors<'a, G: GridBounds + ?Sized> {
+    bounds: &'a G,
+    origin: Location,
+    steps: core::slice::Iter<'a, Vector>,
+}
+
+impl<'a, G: GridBounds + ?Sized> BoundedNeighbors<'a, G> {
+    #[inline]
+    pub(crate) fn new(bounds: &'a G, origin: Location, steps: &'a [Vector]) -> Self {
+        BoundedNeighbors {
+            bounds,
+            origin,
+            steps: steps.iter(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn bounds(&self) -> &'a G {
+        self.bounds
+    }
+
+    #[inline]
+    fn step(&self, step: &Vector) -> Option<Location> {
+        let location = Location {
+            row: self.origin.row.checked_add(step.rows)?,
+            column: self.origin.column.checked_add(step.columns)?,
+        };
+
+        if self.bounds.location_in_bounds(location) {
+            Some(location)
+        } else {
+            None
+        }
+    }
+}
+
+// Custom clone implementation, because BoundedNeighbors is `Clone` even if G
+// is not
+impl<'a, G: GridBounds + ?Sized> Clone for BoundedNeighbors<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            bounds: self.bounds,
+            origin: self.origin,
+            steps: self.steps.clone(),
+        }
+    }
+}
+
+impl<'a, G: GridBounds + ?Sized> Iterator for BoundedNeighbors<'a, G> {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        while let Some(step) = self.steps.next() {
+            if let Some(neighbor) = self.step(step) {
+                return Some(neighbor);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.steps.len()))
+    }
+}
+
+impl<'a, G: GridBounds + ?Sized> DoubleEndedIterator for BoundedNeighbors<'a, G> {
+    fn next_back(&mut self) -> Option<Location> {
+        while let Some(step) = self.steps.next_back() {
+            if let Some(neighbor) = self.step(step) {
+                return Some(neighbor);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, G: GridBounds + ?Sized> FusedIterator for BoundedNeighbors<'a, G> {}
+
 /// An out-of-bounds error for a Location on a grid
 ///
 /// This error is returned by methods that perform bounds checking to indicate
@@ -512,6 +868,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_intersect_bounds() {
+        let overlapping = Window {
+            root: Location::new(-10, 10),
+            dimensions: Vector::new(8, 100),
+        };
+
+        assert_eq!(
+            TEST_WINDOW.intersect_bounds(&overlapping),
+            (Location::new(-5, 10), Vector::new(3, 13))
+        );
+        assert_eq!(
+            overlapping.intersect_bounds(&TEST_WINDOW),
+            TEST_WINDOW.intersect_bounds(&overlapping)
+        );
+        assert_eq!(
+            TEST_WINDOW.intersect_bounds(&TEST_WINDOW),
+            (TEST_WINDOW.root, TEST_WINDOW.dimensions)
+        );
+
+        // Touching along the bottom edge
+        let touching = Window {
+            root: Location::new(5, 3),
+            dimensions: Vector::new(5, 5),
+        };
+
+        assert_eq!(
+            TEST_WINDOW.intersect_bounds(&touching),
+            (Location::new(5, 3), Vector::new(0, 5))
+        );
+
+        // Fully disjoint
+        let disjoint = Window {
+            root: Location::new(20, -20),
+            dimensions: Vector::new(5, 5),
+        };
+
+        assert_eq!(
+            TEST_WINDOW.intersect_bounds(&disjoint),
+            (Location::new(20, 3), Vector::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn test_union_bounds() {
+        let overlapping = Window {
+            root: Location::new(-10, 10),
+            dimensions: Vector::new(8, 100),
+        };
+
+        assert_eq!(
+            TEST_WINDOW.union_bounds(&overlapping),
+            (Location::new(-10, 3), Vector::new(15, 107))
+        );
+        assert_eq!(
+            overlapping.union_bounds(&TEST_WINDOW),
+            TEST_WINDOW.union_bounds(&overlapping)
+        );
+        assert_eq!(
+            TEST_WINDOW.union_bounds(&TEST_WINDOW),
+            (TEST_WINDOW.root, TEST_WINDOW.dimensions)
+        );
+
+        // Fully disjoint
+        let disjoint = Window {
+            root: Location::new(20, -20),
+            dimensions: Vector::new(5, 5),
+        };
+
+        assert_eq!(
+            TEST_WINDOW.union_bounds(&disjoint),
+            (Location::new(-5, -20), Vector::new(30, 43))
+        );
+    }
+
+    #[test]
+    fn test_clamp_location() {
+        for &(row, expected_row_result) in &TEST_ROWS {
+            for &(column, expected_column_result) in &TEST_COLUMNS {
+                let expected_row = match expected_row_result {
+                    Ok(row) | Err(RangeError::TooLow(row)) => row,
+                    Err(RangeError::TooHigh(row)) => row - Rows(1),
+                };
+                let expected_column = match expected_column_result {
+                    Ok(column) | Err(RangeError::TooLow(column)) => column,
+                    Err(RangeError::TooHigh(column)) => column - Columns(1),
+                };
+
+                let clamped = TEST_WINDOW.clamp_location(row + column);
+
+                assert_eq!(clamped, expected_row + expected_column);
+                assert!(TEST_WINDOW.location_in_bounds(clamped));
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let center = Row(0) + Column(10);
+        assert_eq!(TEST_WINDOW.neighbors(center).count(), 4);
+        assert_eq!(TEST_WINDOW.touching_neighbors_in_bounds(center).count(), 8);
+
+        let corner = Row(-5) + Column(3);
+        let mut neighbors = TEST_WINDOW.neighbors(corner);
+        assert!(neighbors
+            .clone()
+            .all(|loc| TEST_WINDOW.location_in_bounds(loc)));
+        assert_eq!(neighbors.clone().count(), 2);
+        assert_eq!(TEST_WINDOW.touching_neighbors_in_bounds(corner).count(), 3);
+
+        let edge = Row(4) + Column(10);
+        assert_eq!(TEST_WINDOW.neighbors(edge).count(), 3);
+        assert_eq!(TEST_WINDOW.touching_neighbors_in_bounds(edge).count(), 5);
+
+        let outside = Row(-7) + Column(10);
+        assert_eq!(TEST_WINDOW.neighbors(outside).count(), 0);
+        assert_eq!(TEST_WINDOW.touching_neighbors_in_bounds(outside).count(), 0);
+
+        let extreme = Row(isize::MIN) + Column(isize::MAX);
+        assert_eq!(TEST_WINDOW.touching_neighbors_in_bounds(extreme).count(), 0);
+
+        assert!(neighbors.next().is_some());
+        assert!(neighbors.next().is_some());
+        assert_eq!(neighbors.next(), None);
+        assert_eq!(neighbors.next(), None);
+    }
+
     #[test]
     fn test_location_in_bounds() {
         for &(row, expected_row_result) in &TEST_ROWS {
@@ -0,0 +1,196 @@
+// Minimal stand-ins for `gridly_grids::VecGrid` and `gridly_grids::SparseGrid`,
+// for use in the doctests in this module. gridly_grids depends on gridly, so
+// gridly can't use it as a dev-dependency without creating a cycle. Include
+// this file in a doctest with:
+//
+// # mod doctest_grids { include!("doctest_grids.rs"); }
+// # use doctest_grids::VecGrid;
+
+use std::collections::HashMap;
+use std::ops::Index;
+
+use gridly::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct VecGrid<T> {
+    dimensions: Vector,
+    storage: Vec<T>,
+}
+
+impl<T> VecGrid<T> {
+    pub fn new(dimensions: impl VectorLike) -> Option<Self>
+    where
+        T: Default,
+    {
+        let dimensions = dimensions.as_vector();
+        let volume = dimensions.rows.0 as usize * dimensions.columns.0 as usize;
+
+        Self::new_row_major(dimensions, (0..volume).map(|_| T::default()))
+    }
+
+    pub fn new_row_major(
+        dimensions: impl VectorLike,
+        input: impl IntoIterator<Item = T>,
+    ) -> Option<Self> {
+        let dimensions = dimensions.as_vector();
+
+        if dimensions.rows < 0 || dimensions.columns < 0 {
+            return None;
+        }
+
+        let volume = dimensions.rows.0 as usize * dimensions.columns.0 as usize;
+        let storage: Vec<T> = input.into_iter().take(volume).collect();
+
+        if storage.len() == volume {
+            Some(VecGrid {
+                dimensions,
+                storage,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn new_from_rows_owned<C: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = C>,
+    ) -> Option<Self> {
+        let mut storage = Vec::new();
+        let mut num_rows = 0;
+        let mut num_columns = None;
+
+        for row in rows {
+            let len = storage.len();
+            storage.extend(row);
+            let row_len = storage.len() - len;
+
+            if *num_columns.get_or_insert(row_len) != row_len {
+                return None;
+            }
+
+            num_rows += 1;
+        }
+
+        Some(VecGrid {
+            dimensions: Vector::new(num_rows, num_columns.unwrap_or(0) as isize),
+            storage,
+        })
+    }
+
+    fn offset_of(&self, location: Location) -> usize {
+        (location.row.0 * self.dimensions.columns.0 + location.column.0) as usize
+    }
+}
+
+impl<T> GridBounds for VecGrid<T> {
+    fn dimensions(&self) -> Vector {
+        self.dimensions
+    }
+
+    fn root(&self) -> Location {
+        Location::zero()
+    }
+}
+
+impl<T> Grid for VecGrid<T> {
+    type Item = T;
+
+    unsafe fn get_unchecked(&self, location: Location) -> &T {
+        self.storage.get_unchecked(self.offset_of(location))
+    }
+}
+
+impl<T> GridMut for VecGrid<T> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut T {
+        let index = self.offset_of(location);
+        self.storage.get_unchecked_mut(index)
+    }
+}
+
+impl<T, L: LocationLike> Index<L> for VecGrid<T> {
+    type Output = T;
+
+    fn index(&self, location: L) -> &T {
+        self.get(location).unwrap()
+    }
+}
+
+impl<T> GridSetter for VecGrid<T> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: T) -> T {
+        let index = self.offset_of(location);
+        std::mem::replace(self.storage.get_unchecked_mut(index), value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: T) {
+        let index = self.offset_of(location);
+        *self.storage.get_unchecked_mut(index) = value;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    root: Location,
+    dimensions: Vector,
+    default: T,
+    storage: HashMap<Location, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new_rooted(root: impl LocationLike, dimensions: impl VectorLike) -> Self
+    where
+        T: Default,
+    {
+        Self::new_rooted_default(root, dimensions, T::default())
+    }
+
+    pub fn new_rooted_default(
+        root: impl LocationLike,
+        dimensions: impl VectorLike,
+        default: T,
+    ) -> Self {
+        SparseGrid {
+            root: root.as_location(),
+            dimensions: dimensions.as_vector(),
+            default,
+            storage: HashMap::new(),
+        }
+    }
+}
+
+impl<T> GridBounds for SparseGrid<T> {
+    fn dimensions(&self) -> Vector {
+        self.dimensions
+    }
+
+    fn root(&self) -> Location {
+        self.root
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Item = T;
+
+    unsafe fn get_unchecked(&self, location: Location) -> &T {
+        self.storage.get(&location).unwrap_or(&self.default)
+    }
+}
+
+impl<T: Clone> GridMut for SparseGrid<T> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut T {
+        let default = &self.default;
+        self.storage
+            .entry(location)
+            .or_insert_with(|| default.clone())
+    }
+}
+
+impl<T: Clone> GridSetter for SparseGrid<T> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: T) -> T {
+        self.storage
+            .insert(location, value)
+            .unwrap_or_else(|| self.default.clone())
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: T) {
+        self.storage.insert(location, value);
+    }
+}
@@ -10,9 +10,11 @@ mod setter;
 mod view;
 mod view_mut;
 
-pub use bounds::{BoundsError, GridBounds};
+pub use bounds::{BoundedNeighbors, BoundsError, GridBounds};
 pub use setter::GridSetter;
 pub use view::{
-    ColumnView, ColumnsView, DisplayAdapter, Grid, RowView, RowsView, SingleView, View,
+    AdjacentPairs, AlignedDisplayAdapter, Cells, CellsWithLocations, ColumnView, ColumnsView,
+    DisplayAdapter, FindAll, Grid, NeighborsBy, OrthogonalSteps, RowView, RowsView, Scanlines,
+    SingleView, Spiral, View, ZipCells,
 };
 pub use view_mut::GridMut;
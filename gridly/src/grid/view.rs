@@ -1,11 +1,23 @@
 use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::hash::{Hash, Hasher};
 use core::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator};
 use core::marker::PhantomData;
 use core::ops::Index;
 
-use crate::grid::{BoundsError, GridBounds};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::direction::{Direction, Right, EACH_DIRECTION};
+use crate::grid::{BoundedNeighbors, BoundsError, GridBounds};
 use crate::location::{Column, Component as LocComponent, Location, LocationLike, Row};
-use crate::range::{ColumnRangeError, ComponentRange, LocationRange, RangeError, RowRangeError};
+use crate::range::{
+    ColumnRangeError, ComponentRange, CrossRange, LocationRange, RangeError, RowRange,
+    RowRangeError,
+};
+use crate::vector::{Columns, Rows, Vector};
 
 // Add a usize to an isize, return an isize. Overflows if necessary.
 
@@ -114,6 +126,442 @@ pub trait Grid: GridBounds {
         self.single_view(column.into())
     }
 
+    /// Get an iterator over the rows of the grid, in order, where each row
+    /// is paired with its [`Row`] index. This is intended for renderers and
+    /// other code that works a scanline at a time and needs the index of each
+    /// row while iterating over its cells.
+    ///
+    /// Generic grids can't provide rows as contiguous slices; grids that can
+    /// (such as `VecGrid`, via `VecGrid::scanline_slices`) may provide their
+    /// own slice-based equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(2)), "abcd".chars()).unwrap();
+    /// let mut scanlines = grid.scanlines();
+    ///
+    /// let (row, view) = scanlines.next().unwrap();
+    /// assert_eq!(row, Row(0));
+    /// assert_eq!(view.iter().collect::<String>(), "ab");
+    ///
+    /// let (row, view) = scanlines.next().unwrap();
+    /// assert_eq!(row, Row(1));
+    /// assert_eq!(view.iter().collect::<String>(), "cd");
+    ///
+    /// assert!(scanlines.next().is_none());
+    /// ```
+    #[inline]
+    fn scanlines(&self) -> Scanlines<'_, Self> {
+        Scanlines {
+            grid: self,
+            range: self.row_range(),
+        }
+    }
+
+    /// Get the run-length encoding of a row of the grid, as a list of
+    /// `(count, cell)` pairs, where each pair is a run of `count` consecutive
+    /// equal cells. Returns an error if the row is out of bounds.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "AABAAA".chars(),
+    ///     "BBBBBB".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(grid.row_runs(0).unwrap(), [(2, &'A'), (1, &'B'), (3, &'A')]);
+    /// assert_eq!(grid.row_runs(1).unwrap(), [(6, &'B')]);
+    /// assert!(grid.row_runs(2).is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn row_runs(&self, row: impl Into<Row>) -> Result<Vec<(usize, &Self::Item)>, RowRangeError>
+    where
+        Self::Item: PartialEq,
+    {
+        let mut runs: Vec<(usize, &Self::Item)> = Vec::new();
+
+        self.row(row)?
+            .iter()
+            .for_each(|cell| match runs.last_mut() {
+                Some((count, run_cell)) if *run_cell == cell => *count += 1,
+                _ => runs.push((1, cell)),
+            });
+
+        Ok(runs)
+    }
+
+    /// Reduce each row of the grid to a single value, by folding across the
+    /// cells of the row from left to right. Each fold starts with a fresh
+    /// accumulator from `init`. Returns one accumulator per row, from top to
+    /// bottom.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    ///
+    /// assert_eq!(grid.reduce_rows(|| 0, |sum, cell| sum + cell), [6, 15]);
+    /// assert_eq!(grid.reduce_rows(|| 0, |max, &cell| max.max(cell)), [3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn reduce_rows<A>(
+        &self,
+        init: impl Fn() -> A,
+        mut func: impl FnMut(A, &Self::Item) -> A,
+    ) -> Vec<A> {
+        self.rows()
+            .iter()
+            .map(|row| row.iter().fold(init(), &mut func))
+            .collect()
+    }
+
+    /// Reduce each column of the grid to a single value, by folding down the
+    /// cells of the column from top to bottom. Each fold starts with a fresh
+    /// accumulator from `init`. Returns one accumulator per column, from left
+    /// to right.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    ///
+    /// assert_eq!(grid.reduce_columns(|| 0, |sum, cell| sum + cell), [5, 7, 9]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn reduce_columns<A>(
+        &self,
+        init: impl Fn() -> A,
+        mut func: impl FnMut(A, &Self::Item) -> A,
+    ) -> Vec<A> {
+        self.columns()
+            .iter()
+            .map(|column| column.iter().fold(init(), &mut func))
+            .collect()
+    }
+
+    /// Collect clones of every cell in the grid into a [`HashMap`], keyed by
+    /// location. This is useful for interoperating with algorithms and crates
+    /// that work with map-based grids.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    /// let map = grid.to_hashmap();
+    ///
+    /// assert_eq!(map.len(), 6);
+    /// assert_eq!(map[&Location::new(1, 2)], 6);
+    /// assert_eq!(map.get(&Location::new(2, 0)), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_hashmap(&self) -> HashMap<Location, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.to_hashmap_where(|_| true)
+    }
+
+    /// Collect clones of the cells in the grid that match a predicate into a
+    /// [`HashMap`], keyed by location.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    /// let evens = grid.to_hashmap_where(|&cell| cell % 2 == 0);
+    ///
+    /// assert_eq!(evens.len(), 3);
+    /// assert_eq!(evens[&Location::new(0, 1)], 2);
+    /// assert_eq!(evens.get(&Location::new(0, 0)), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_hashmap_where(
+        &self,
+        mut pred: impl FnMut(&Self::Item) -> bool,
+    ) -> HashMap<Location, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.rows()
+            .iter()
+            .flat_map(|row| row.iter_with_locations())
+            .filter(|(_, cell)| pred(cell))
+            .map(|(location, cell)| (location, cell.clone()))
+            .collect()
+    }
+
+    /// Check if every cell in the grid satisfies a predicate. Cells are
+    /// checked in row-major order, and the check stops at the first cell that
+    /// fails the predicate. Returns `true` for an empty grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    ///
+    /// assert!(grid.all_cells(|&cell| cell > 0));
+    /// assert!(!grid.all_cells(|&cell| cell < 6));
+    ///
+    /// let empty: VecGrid<i32> = VecGrid::new((Rows(0), Columns(0))).unwrap();
+    /// assert!(empty.all_cells(|_| false));
+    /// ```
+    #[inline]
+    fn all_cells(&self, mut pred: impl FnMut(&Self::Item) -> bool) -> bool {
+        self.rows().iter().all(move |row| row.iter().all(&mut pred))
+    }
+
+    /// Check if any cell in the grid satisfies a predicate. Cells are checked
+    /// in row-major order, and the check stops at the first cell that passes
+    /// the predicate. Returns `false` for an empty grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(2)), vec![3, 0, 1, 2]).unwrap();
+    ///
+    /// assert!(grid.any_cell(|&cell| cell == 0));
+    /// assert!(!grid.any_cell(|&cell| cell > 3));
+    ///
+    /// let empty: VecGrid<i32> = VecGrid::new((Rows(0), Columns(0))).unwrap();
+    /// assert!(!empty.any_cell(|_| true));
+    /// ```
+    #[inline]
+    fn any_cell(&self, mut pred: impl FnMut(&Self::Item) -> bool) -> bool {
+        self.rows().iter().any(move |row| row.iter().any(&mut pred))
+    }
+
+    /// Check if this grid has the same content as another grid. Two grids have
+    /// the same content if they have the same dimensions, and each cell in
+    /// this grid compares equal to the cell at the same offset from the root
+    /// in the other grid. The roots of the grids are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::{SparseGrid, VecGrid};
+    ///
+    /// let grid1 = VecGrid::new_row_major((Rows(2), Columns(2)), vec![0, 1, 0, 0]).unwrap();
+    ///
+    /// let mut grid2: SparseGrid<i32> = SparseGrid::new_rooted((5, -3), (Rows(2), Columns(2)));
+    /// grid2.set((5, -2), 1).unwrap();
+    ///
+    /// assert!(grid1.content_eq(&grid2));
+    ///
+    /// grid2.set((6, -2), 1).unwrap();
+    /// assert!(!grid1.content_eq(&grid2));
+    ///
+    /// let grid3 = VecGrid::new_row_major((Rows(1), Columns(4)), vec![0, 1, 0, 0]).unwrap();
+    /// assert!(!grid1.content_eq(&grid3));
+    /// ```
+    fn content_eq<G>(&self, other: &G) -> bool
+    where
+        G: Grid + ?Sized,
+        Self::Item: PartialEq<G::Item>,
+    {
+        self.dimensions() == other.dimensions()
+            && self
+                .rows()
+                .iter()
+                .zip(other.rows().iter())
+                .all(|(row, other_row)| row.iter().eq(other_row.iter()))
+    }
+
+    /// Check if this grid is symmetric across its central column; that is, if
+    /// each row reads the same forwards and backwards. For grids with an odd
+    /// number of columns, the central column is its own reflection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#.#".chars(),
+    ///     ".#.".chars(),
+    ///     "##.".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert!(!grid.has_vertical_symmetry());
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#..#".chars(),
+    ///     ".##.".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert!(grid.has_vertical_symmetry());
+    /// ```
+    fn has_vertical_symmetry(&self) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        self.rows()
+            .iter()
+            .all(|row| row.iter().eq(row.iter().rev()))
+    }
+
+    /// Check if this grid is symmetric across its central row; that is, if
+    /// each column reads the same forwards and backwards. For grids with an
+    /// odd number of rows, the central row is its own reflection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#.#".chars(),
+    ///     ".#.".chars(),
+    ///     "#.#".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert!(grid.has_horizontal_symmetry());
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#..#".chars(),
+    ///     ".##.".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert!(!grid.has_horizontal_symmetry());
+    /// ```
+    fn has_horizontal_symmetry(&self) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        self.columns()
+            .iter()
+            .all(|column| column.iter().eq(column.iter().rev()))
+    }
+
+    /// Feed the content of this grid into a [`Hasher`]: first the grid's
+    /// dimensions, then each cell, in row-major order. The root of the grid is
+    /// ignored, which means that two grids for which
+    /// [`content_eq`][Grid::content_eq] is true will produce the same hash.
+    /// This is useful for storing grid states in a hash table, such as when
+    /// searching over grid configurations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::SparseGrid;
+    ///
+    /// fn hash(grid: &impl Grid<Item=char>) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     grid.content_hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let mut grid1 = SparseGrid::new_rooted_default((0, 0), (Rows(3), Columns(3)), '.');
+    /// grid1.set((1, 1), '#').unwrap();
+    ///
+    /// let mut grid2 = SparseGrid::new_rooted_default((-10, 4), (Rows(3), Columns(3)), '.');
+    /// grid2.set((-9, 5), '#').unwrap();
+    ///
+    /// assert_eq!(hash(&grid1), hash(&grid2));
+    /// ```
+    fn content_hash<H: Hasher>(&self, state: &mut H)
+    where
+        Self::Item: Hash,
+    {
+        self.dimensions().hash(state);
+        self.rows()
+            .iter()
+            .for_each(|row| row.iter().for_each(|cell| cell.hash(state)));
+    }
+
+    /// Get an iterator over every pair of orthogonally adjacent cells in the
+    /// grid, each paired with its location. Each pair is produced exactly
+    /// once, with the top or left cell first and its bottom or right neighbor
+    /// second. All of the horizontal pairs are produced first, in row-major
+    /// order, followed by all of the vertical pairs, also in row-major order.
+    /// This is useful for building a graph of the grid, where each pair is an
+    /// edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(2)), "abcd".chars()).unwrap();
+    ///
+    /// let pairs: Vec<(char, char)> = grid
+    ///     .adjacent_pairs()
+    ///     .map(|((_, &first), (_, &second))| (first, second))
+    ///     .collect();
+    ///
+    /// assert_eq!(pairs, [('a', 'b'), ('c', 'd'), ('a', 'c'), ('b', 'd')]);
+    ///
+    /// let ((loc1, _), (loc2, _)) = grid.adjacent_pairs().last().unwrap();
+    /// assert_eq!(loc1, (0, 1));
+    /// assert_eq!(loc2, (1, 1));
+    /// ```
+    #[inline]
+    fn adjacent_pairs(&self) -> AdjacentPairs<'_, Self> {
+        AdjacentPairs {
+            grid: self,
+            root: self.root(),
+            outer: self.outer_bound(),
+            next: self.root(),
+            vertical: false,
+        }
+    }
+
     /// Make a grid [`Display`]able, using a function that defines how each of its
     /// cells are printed. For each row, the adapter simply prints each cell
     /// in the row, followed by a newline.
@@ -129,6 +577,361 @@ pub trait Grid: GridBounds {
     {
         DisplayAdapter { grid: self, func }
     }
+
+    /// Make a grid of [`Display`]able cells printable, with each column
+    /// right-aligned. The adapter first measures the widest rendered cell in
+    /// the grid, then prints each cell padded to that width, separated by a
+    /// single space, with each row followed by a newline. This makes numeric
+    /// grids much more readable than [`display_with`][Grid::display_with].
+    ///
+    /// Each cell is formatted twice: once to measure it, and once to print it.
+    /// Widths are measured in `char`s. This doesn't require any allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major(
+    ///     (Rows(2), Columns(3)),
+    ///     [1, 20, 3, 40, 5, -6].iter().copied(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(grid.display_aligned().to_string(), " 1 20  3\n40  5 -6\n");
+    /// ```
+    #[inline]
+    fn display_aligned(&self) -> AlignedDisplayAdapter<&Self>
+    where
+        Self::Item: Display,
+    {
+        AlignedDisplayAdapter { grid: self }
+    }
+
+    /// Get an iterator over the locations of every cell in the grid that
+    /// matches the predicate `pred`, in row-major order. The iterator is lazy
+    /// and doesn't allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#..".chars(),
+    ///     ".##".chars(),
+    /// ]).unwrap();
+    ///
+    /// let walls: Vec<Location> = grid.find_all(|&cell| cell == '#').collect();
+    /// assert_eq!(walls, [Location::new(0, 0), Location::new(1, 1), Location::new(1, 2)]);
+    ///
+    /// assert_eq!(grid.find_all(|&cell| cell == '@').next(), None);
+    /// ```
+    #[inline]
+    fn find_all<F>(&self, pred: F) -> FindAll<'_, Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        FindAll {
+            grid: self,
+            locations: CrossRange::new(self.row_range(), self.column_range()),
+            pred,
+        }
+    }
+
+    /// Get an iterator over the cells of the grid, paired with their
+    /// locations, in an outward clockwise spiral from `start`. The spiral
+    /// first steps right, then down, then left twice, then up twice, then
+    /// right three times, and so on. Locations that are out of bounds are
+    /// skipped, and the iterator ends once every cell in the grid has been
+    /// visited. `start` itself doesn't need to be in bounds, but note that the
+    /// spiral still walks every location in its path, so starting `d` cells
+    /// away from the grid means stepping over roughly `d * d` out-of-bounds
+    /// locations before the first cell is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "abc".chars(),
+    ///     "def".chars(),
+    ///     "ghi".chars(),
+    /// ]).unwrap();
+    ///
+    /// let spiral: String = grid.spiral_from((1, 1)).map(|(_, &cell)| cell).collect();
+    /// assert_eq!(spiral, "efihgdabc");
+    ///
+    /// let spiral: String = grid.spiral_from((0, 0)).map(|(_, &cell)| cell).collect();
+    /// assert_eq!(spiral, "abedcfihg");
+    ///
+    /// let (location, _) = grid.spiral_from((1, 1)).last().unwrap();
+    /// assert_eq!(location, (0, 2));
+    /// ```
+    #[inline]
+    fn spiral_from(&self, start: impl LocationLike) -> Spiral<'_, Self> {
+        let dimensions = self.dimensions();
+
+        Spiral {
+            grid: self,
+            next: start.as_location(),
+            direction: Right,
+            leg_length: 1,
+            leg_progress: 0,
+            second_leg: false,
+            remaining: (dimensions.rows.0.max(0) as usize)
+                .saturating_mul(dimensions.columns.0.max(0) as usize),
+        }
+    }
+
+    /// Get an iterator over the in-bounds orthogonal neighbors of `location`,
+    /// as `(direction, location, cell)` triples, where `direction` is the
+    /// direction of the step from `location` to the neighbor. Neighbors are
+    /// produced in the order of [`EACH_DIRECTION`]: up, right, down, left.
+    /// This is useful for pathfinding, where it's necessary to remember how
+    /// each step was taken.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "abc".chars(),
+    ///     "def".chars(),
+    ///     "ghi".chars(),
+    /// ]).unwrap();
+    ///
+    /// let steps: Vec<(Direction, Location, char)> = grid
+    ///     .orthogonal_steps((1, 1))
+    ///     .map(|(direction, location, &cell)| (direction, location, cell))
+    ///     .collect();
+    ///
+    /// assert_eq!(steps, [
+    ///     (Up, Location::new(0, 1), 'b'),
+    ///     (Right, Location::new(1, 2), 'f'),
+    ///     (Down, Location::new(2, 1), 'h'),
+    ///     (Left, Location::new(1, 0), 'd'),
+    /// ]);
+    ///
+    /// let directions: Vec<Direction> = grid
+    ///     .orthogonal_steps((0, 2))
+    ///     .map(|(direction, _, _)| direction)
+    ///     .collect();
+    ///
+    /// assert_eq!(directions, [Down, Left]);
+    /// ```
+    #[inline]
+    fn orthogonal_steps(&self, location: impl LocationLike) -> OrthogonalSteps<'_, Self> {
+        OrthogonalSteps {
+            grid: self,
+            origin: location.as_location(),
+            directions: EACH_DIRECTION.iter(),
+        }
+    }
+
+    /// Get an iterator over the in-bounds neighbors of `location`, as
+    /// `(location, cell)` pairs, where the neighbors are given by an arbitrary
+    /// set of offset `steps`. This allows for non-standard adjacencies, such
+    /// as [`KNIGHT_MOVES`][crate::vector::KNIGHT_MOVES]. Neighbors are
+    /// produced in the same order as `steps`; out of bounds neighbors are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(5), Columns(5)), 0..).unwrap();
+    ///
+    /// let corner: Vec<Location> = grid
+    ///     .neighbors_by((0, 0), &KNIGHT_MOVES)
+    ///     .map(|(location, _)| location)
+    ///     .collect();
+    ///
+    /// assert_eq!(corner.len(), 2);
+    /// assert!(corner.contains(&Location::new(1, 2)));
+    /// assert!(corner.contains(&Location::new(2, 1)));
+    ///
+    /// assert_eq!(grid.neighbors_by((2, 2), &KNIGHT_MOVES).count(), 8);
+    ///
+    /// let steps = [Vector::new(0, 3), Vector::new(0, -3)];
+    /// let cells: Vec<&i32> = grid.neighbors_by((1, 1), &steps).map(|(_, cell)| cell).collect();
+    /// assert_eq!(cells, [&9]);
+    /// ```
+    #[inline]
+    fn neighbors_by<'a>(
+        &'a self,
+        location: impl LocationLike,
+        steps: &'a [Vector],
+    ) -> NeighborsBy<'a, Self> {
+        NeighborsBy {
+            neighbors: BoundedNeighbors::new(self, location.as_location(), steps),
+        }
+    }
+
+    /// Count the perimeter of the cells that satisfy a predicate. For each
+    /// matching cell, this counts how many of its four orthogonal sides border
+    /// a non-matching cell or the edge of the grid, and returns the sum over
+    /// all matching cells. This is the standard perimeter computation for
+    /// regions of a grid, such as the total fence length around a garden.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "AAB".chars(),
+    ///     "AAB".chars(),
+    ///     "BBA".chars(),
+    /// ]).unwrap();
+    ///
+    /// // A solid 2x2 block
+    /// assert_eq!(grid.perimeter_where(|&cell| cell == 'A'), 8 + 4);
+    /// assert_eq!(grid.perimeter_where(|&cell| cell == 'B'), 6 + 6);
+    /// assert_eq!(grid.perimeter_where(|_| true), 12);
+    /// assert_eq!(grid.perimeter_where(|_| false), 0);
+    /// ```
+    fn perimeter_where(&self, pred: impl Fn(&Self::Item) -> bool) -> usize {
+        self.find_all(&pred)
+            .map(|location| {
+                EACH_DIRECTION
+                    .iter()
+                    .filter(|&&direction| {
+                        !location
+                            .checked_step(direction)
+                            .and_then(|neighbor| self.get(neighbor).ok())
+                            .is_some_and(&pred)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Get an iterator over the cells of this grid and `other` that share a
+    /// location, as `(location, cell, other_cell)` triples. Only the
+    /// locations in the intersection of the two grids' bounds (see
+    /// [`intersect_bounds`][GridBounds::intersect_bounds]) are visited, in
+    /// row-major order. This is lazy and doesn't allocate, so it can be used
+    /// to stream a comparison or combination of two grids without building a
+    /// third.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::{SparseGrid, VecGrid};
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    ///
+    /// let mut other = SparseGrid::new_rooted_default((1, 1), (Rows(2), Columns(2)), 0);
+    /// other.set((1, 1), 10).unwrap();
+    /// other.set((1, 2), 20).unwrap();
+    ///
+    /// let cells: Vec<(Location, i32, i32)> = grid
+    ///     .zip_cells(&other)
+    ///     .map(|(location, &a, &b)| (location, a, b))
+    ///     .collect();
+    ///
+    /// assert_eq!(cells, [
+    ///     (Location::new(1, 1), 5, 10),
+    ///     (Location::new(1, 2), 6, 20),
+    /// ]);
+    ///
+    /// let far = SparseGrid::new_rooted_default((10, 10), (Rows(2), Columns(2)), 0);
+    /// assert_eq!(grid.zip_cells(&far).len(), 0);
+    /// ```
+    #[inline]
+    fn zip_cells<'a, H: Grid>(&'a self, other: &'a H) -> ZipCells<'a, Self, H> {
+        let (root, dimensions) = self.intersect_bounds(other);
+
+        ZipCells {
+            grid: self,
+            other,
+            locations: CrossRange::new(
+                root.row.span(dimensions.rows),
+                root.column.span(dimensions.columns),
+            ),
+        }
+    }
+
+    /// Get an iterator over all the cells in the grid, in row-major order.
+    /// This is equivalent to iterating over each row in [`rows`][Grid::rows],
+    /// then over each cell in that row, but it's a single flat, double-ended,
+    /// exact-size iterator. See [`iter_with_locations`][Grid::iter_with_locations]
+    /// to also get the location of each cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::SparseGrid;
+    ///
+    /// let mut grid = SparseGrid::new_rooted((1, 1), (Rows(2), Columns(3)));
+    /// grid.set((1, 2), 5).unwrap();
+    /// grid.set((2, 1), 10).unwrap();
+    ///
+    /// let mut cells = grid.cells();
+    /// assert_eq!(cells.size_hint(), (6, Some(6)));
+    ///
+    /// let cells: Vec<i32> = cells.copied().collect();
+    /// assert_eq!(cells, [0, 5, 0, 10, 0, 0]);
+    ///
+    /// assert_eq!(grid.cells().rev().next(), Some(&0));
+    /// assert_eq!(grid.cells().filter(|&&cell| cell > 0).count(), 2);
+    /// ```
+    #[inline]
+    fn cells(&self) -> Cells<'_, Self> {
+        Cells {
+            inner: self.iter_with_locations(),
+        }
+    }
+
+    /// Get an iterator over all the cells in the grid, paired with their
+    /// locations, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::SparseGrid;
+    ///
+    /// let mut grid = SparseGrid::new_rooted((-1, 3), (Rows(2), Columns(2)));
+    /// grid.set((0, 4), 'x').unwrap();
+    ///
+    /// let mut cells = grid.iter_with_locations();
+    /// assert_eq!(cells.len(), 4);
+    ///
+    /// assert_eq!(cells.next(), Some((Location::new(-1, 3), &'\0')));
+    /// assert_eq!(cells.next(), Some((Location::new(-1, 4), &'\0')));
+    /// assert_eq!(cells.next(), Some((Location::new(0, 3), &'\0')));
+    /// assert_eq!(cells.size_hint(), (1, Some(1)));
+    /// assert_eq!(cells.next(), Some((Location::new(0, 4), &'x')));
+    /// assert_eq!(cells.next(), None);
+    /// ```
+    #[inline]
+    fn iter_with_locations(&self) -> CellsWithLocations<'_, Self> {
+        CellsWithLocations {
+            grid: self,
+            locations: CrossRange::new(self.row_range(), self.column_range()),
+        }
+    }
 }
 
 impl<G: Grid> Grid for &G {
@@ -437,45 +1240,675 @@ impl<'a, G: Grid + ?Sized> ColumnView<'a, G> {
     }
 }
 
-/// A wrapper around a grid, allowing it to be printed via [`Display`]. See
-/// [`Grid`]`::`[`display_with`][Grid::display_with] for details.
-#[derive(Debug, Copy, Clone)]
-pub struct DisplayAdapter<T, F> {
-    func: F,
-    grid: T,
+/// An iterator over the rows of a grid, paired with their indexes. See
+/// [`Grid`]`::`[`scanlines`][Grid::scanlines] for details.
+#[derive(Debug)]
+pub struct Scanlines<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+    range: RowRange,
 }
 
-impl<T, F, R> Display for DisplayAdapter<T, F>
-where
-    T: Grid,
-    F: Fn(&T::Item) -> R,
-    R: Display,
-{
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let func = &self.func;
+impl<'a, G: Grid + ?Sized> Scanlines<'a, G> {
+    #[inline]
+    fn scanline(&self, row: Row) -> (Row, RowView<'a, G>) {
+        // Safety: the range is always a subset of the grid's row range
+        (row, unsafe { self.grid.row_unchecked(row) })
+    }
+}
 
-        self.grid.rows().iter().try_for_each(move |row| {
-            row.iter().map(func).try_for_each(|cell| cell.fmt(f))?;
-            f.write_char('\n')
-        })
+// Custom clone implementation, because Scanlines is `Clone` even if G is not
+impl<'a, G: Grid + ?Sized> Clone for Scanlines<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            range: self.range.clone(),
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::grid::BoundsError;
-    use crate::prelude::*;
-    use crate::range::{ColumnRangeError, RangeError, RowRangeError};
+impl<'a, G: Grid + ?Sized> Iterator for Scanlines<'a, G> {
+    type Item = (Row, RowView<'a, G>);
 
-    // A stack-allocated grid with a fixed size of three rows by two columns.
-    // The root of this grid is (-1, 0), which means that the valid rows are
-    // [-1, 0, 1] and the valid columns are [0, 1]
-    #[derive(Debug, Eq, PartialEq)]
-    struct ThreeByTwo<T> {
-        rows: [[T; 2]; 3],
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|row| self.scanline(row))
     }
 
-    impl<T> GridBounds for ThreeByTwo<T> {
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|row| self.scanline(row))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> DoubleEndedIterator for Scanlines<'a, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|row| self.scanline(row))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth_back(n).map(|row| self.scanline(row))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> ExactSizeIterator for Scanlines<'a, G> {}
+impl<'a, G: Grid + ?Sized> FusedIterator for Scanlines<'a, G> {}
+
+/// An iterator over the pairs of adjacent cells in a grid. See
+/// [`Grid`]`::`[`adjacent_pairs`][Grid::adjacent_pairs] for details.
+#[derive(Debug)]
+pub struct AdjacentPairs<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+    root: Location,
+    outer: Location,
+
+    // The location of the first cell of the next pair. This location may be
+    // out of bounds; `next` skips ahead to the next valid pair.
+    next: Location,
+
+    // False while producing horizontal pairs, true while producing vertical
+    // pairs.
+    vertical: bool,
+}
+
+// Custom clone implementation, because AdjacentPairs is `Clone` even if G is not
+impl<'a, G: Grid + ?Sized> Clone for AdjacentPairs<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            root: self.root,
+            outer: self.outer,
+            next: self.next,
+            vertical: self.vertical,
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for AdjacentPairs<'a, G> {
+    type Item = ((Location, &'a G::Item), (Location, &'a G::Item));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = self.next;
+
+            if first.row >= self.outer.row {
+                if self.vertical {
+                    return None;
+                }
+
+                self.vertical = true;
+                self.next = self.root;
+                continue;
+            }
+
+            let second = if self.vertical {
+                first + Rows(1)
+            } else {
+                first + Columns(1)
+            };
+
+            if second.column >= self.outer.column {
+                self.next = Location::new(first.row + Rows(1), self.root.column);
+                continue;
+            }
+
+            if second.row >= self.outer.row {
+                // The last row has no cells below it, so we're done
+                self.next.row = self.outer.row;
+                return None;
+            }
+
+            self.next = first + Columns(1);
+
+            // Safety: both locations are between the root and the outer
+            // bound of the grid.
+            return Some(unsafe {
+                (
+                    (first, self.grid.get_unchecked(first)),
+                    (second, self.grid.get_unchecked(second)),
+                )
+            });
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> FusedIterator for AdjacentPairs<'a, G> {}
+
+/// An iterator over the locations of the cells in a grid that match a
+/// predicate. See [`Grid`]`::`[`find_all`][Grid::find_all] for details.
+#[derive(Debug)]
+pub struct FindAll<'a, G: Grid + ?Sized, F> {
+    grid: &'a G,
+    locations: CrossRange<Row>,
+    pred: F,
+}
+
+// Custom clone implementation, because FindAll is `Clone` even if G is not
+impl<'a, G: Grid + ?Sized, F: Clone> Clone for FindAll<'a, G, F> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            locations: self.locations.clone(),
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<'a, G, F> Iterator for FindAll<'a, G, F>
+where
+    G: Grid + ?Sized,
+    F: FnMut(&G::Item) -> bool,
+{
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        let grid = self.grid;
+        let pred = &mut self.pred;
+
+        // Safety: the locations are always within the grid's bounds
+        self.locations
+            .find(|&location| pred(unsafe { grid.get_unchecked(location) }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.locations.size_hint().1)
+    }
+}
+
+impl<'a, G, F> DoubleEndedIterator for FindAll<'a, G, F>
+where
+    G: Grid + ?Sized,
+    F: FnMut(&G::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Location> {
+        let grid = self.grid;
+        let pred = &mut self.pred;
+
+        // Safety: the locations are always within the grid's bounds
+        self.locations
+            .rfind(|&location| pred(unsafe { grid.get_unchecked(location) }))
+    }
+}
+
+impl<'a, G, F> FusedIterator for FindAll<'a, G, F>
+where
+    G: Grid + ?Sized,
+    F: FnMut(&G::Item) -> bool,
+{
+}
+
+/// An iterator over the cells of a grid in an outward clockwise spiral. See
+/// [`Grid`]`::`[`spiral_from`][Grid::spiral_from] for details.
+#[derive(Debug)]
+pub struct Spiral<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+
+    // The next location in the spiral. This location may be out of bounds;
+    // `next` skips ahead to the next in-bounds location.
+    next: Location,
+    direction: Direction,
+
+    // The spiral is made of pairs of legs of the same length: 1 right, 1 down,
+    // 2 left, 2 up, 3 right, etc.
+    leg_length: isize,
+    leg_progress: isize,
+    second_leg: bool,
+
+    // The number of in-bounds cells that haven't been visited yet
+    remaining: usize,
+}
+
+impl<'a, G: Grid + ?Sized> Spiral<'a, G> {
+    fn advance(&mut self) {
+        self.next += self.direction;
+        self.leg_progress += 1;
+
+        if self.leg_progress == self.leg_length {
+            self.leg_progress = 0;
+            self.direction = self.direction.clockwise();
+
+            if self.second_leg {
+                self.leg_length += 1;
+            }
+
+            self.second_leg = !self.second_leg;
+        }
+    }
+}
+
+// Custom clone implementation, because Spiral is `Clone` even if G is not
+impl<'a, G: Grid + ?Sized> Clone for Spiral<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            next: self.next,
+            direction: self.direction,
+            leg_length: self.leg_length,
+            leg_progress: self.leg_progress,
+            second_leg: self.second_leg,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for Spiral<'a, G> {
+    type Item = (Location, &'a G::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let location = self.next;
+            self.advance();
+
+            if let Ok(cell) = self.grid.get(location) {
+                self.remaining -= 1;
+                return Some((location, cell));
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> ExactSizeIterator for Spiral<'a, G> {}
+impl<'a, G: Grid + ?Sized> FusedIterator for Spiral<'a, G> {}
+
+/// An iterator over the in-bounds orthogonal neighbors of a location. See
+/// [`Grid`]`::`[`orthogonal_steps`][Grid::orthogonal_steps] for details.
+#[derive(Debug)]
+pub struct OrthogonalSteps<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+    origin: Location,
+    directions: core::slice::Iter<'static, Direction>,
+}
+
+impl<'a, G: Grid + ?Sized> OrthogonalSteps<'a, G> {
+    #[inline]
+    fn step(&self, direction: Direction) -> Option<(Direction, Location, &'a G::Item)> {
+        let location = self.origin.checked_step(direction)?;
+        let cell = self.grid.get(location).ok()?;
+
+        Some((direction, location, cell))
+    }
+}
+
+// Custom clone implementation, because OrthogonalSteps is `Clone` even if G
+// is not
+impl<'a, G: Grid + ?Sized> Clone for OrthogonalSteps<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            origin: self.origin,
+            directions: self.directions.clone(),
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for OrthogonalSteps<'a, G> {
+    type Item = (Direction, Location, &'a G::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&direction) = self.directions.next() {
+            if let Some(step) = self.step(direction) {
+                return Some(step);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.directions.len()))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> DoubleEndedIterator for OrthogonalSteps<'a, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(&direction) = self.directions.next_back() {
+            if let Some(step) = self.step(direction) {
+                return Some(step);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, G: Grid + ?Sized> FusedIterator for OrthogonalSteps<'a, G> {}
+
+/// An iterator over the in-bounds neighbors of a location, given by a set of
+/// offsets. See [`Grid`]`::`[`neighbors_by`][Grid::neighbors_by] for details.
+#[derive(Debug)]
+pub struct NeighborsBy<'a, G: Grid + ?Sized> {
+    neighbors: BoundedNeighbors<'a, G>,
+}
+
+impl<'a, G: Grid + ?Sized> NeighborsBy<'a, G> {
+    #[inline]
+    fn get(&self, location: Location) -> (Location, &'a G::Item) {
+        // Safety: BoundedNeighbors only yields locations that are in bounds
+        (location, unsafe {
+            self.neighbors.bounds().get_unchecked(location)
+        })
+    }
+}
+
+// Custom clone implementation, because NeighborsBy is `Clone` even if G is
+// not
+impl<'a, G: Grid + ?Sized> Clone for NeighborsBy<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            neighbors: self.neighbors.clone(),
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for NeighborsBy<'a, G> {
+    type Item = (Location, &'a G::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let location = self.neighbors.next()?;
+        Some(self.get(location))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.neighbors.size_hint()
+    }
+}
+
+impl<'a, G: Grid + ?Sized> DoubleEndedIterator for NeighborsBy<'a, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let location = self.neighbors.next_back()?;
+        Some(self.get(location))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> FusedIterator for NeighborsBy<'a, G> {}
+
+/// An iterator over the cells of two grids that share a location. See
+/// [`Grid`]`::`[`zip_cells`][Grid::zip_cells] for details.
+#[derive(Debug)]
+pub struct ZipCells<'a, G: Grid + ?Sized, H: Grid + ?Sized> {
+    grid: &'a G,
+    other: &'a H,
+    locations: CrossRange<Row>,
+}
+
+// Custom clone implementation, because ZipCells is `Clone` even if G and H
+// are not
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> Clone for ZipCells<'a, G, H> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            other: self.other,
+            locations: self.locations.clone(),
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> ZipCells<'a, G, H> {
+    #[inline]
+    fn cells(&self, location: Location) -> (Location, &'a G::Item, &'a H::Item) {
+        // Safety: the locations are always within the bounds of both grids
+        unsafe {
+            (
+                location,
+                self.grid.get_unchecked(location),
+                self.other.get_unchecked(location),
+            )
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> Iterator for ZipCells<'a, G, H> {
+    type Item = (Location, &'a G::Item, &'a H::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.locations.next().map(|location| self.cells(location))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.locations.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.locations.nth(n).map(|location| self.cells(location))
+    }
+}
+
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> DoubleEndedIterator for ZipCells<'a, G, H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.locations
+            .next_back()
+            .map(|location| self.cells(location))
+    }
+}
+
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> ExactSizeIterator for ZipCells<'a, G, H> {}
+impl<'a, G: Grid + ?Sized, H: Grid + ?Sized> FusedIterator for ZipCells<'a, G, H> {}
+
+/// An iterator over all the cells of a grid, paired with their locations, in
+/// row-major order. See [`Grid`]`::`[`iter_with_locations`][Grid::iter_with_locations]
+/// for details.
+#[derive(Debug)]
+pub struct CellsWithLocations<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+    locations: CrossRange<Row>,
+}
+
+// Custom clone implementation, because CellsWithLocations is `Clone` even if
+// G is not
+impl<'a, G: Grid + ?Sized> Clone for CellsWithLocations<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            locations: self.locations.clone(),
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> CellsWithLocations<'a, G> {
+    #[inline]
+    fn cell(&self, location: Location) -> (Location, &'a G::Item) {
+        // Safety: the locations are always within the bounds of the grid
+        (location, unsafe { self.grid.get_unchecked(location) })
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for CellsWithLocations<'a, G> {
+    type Item = (Location, &'a G::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.locations.next().map(|location| self.cell(location))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.locations.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.locations.nth(n).map(|location| self.cell(location))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> DoubleEndedIterator for CellsWithLocations<'a, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.locations
+            .next_back()
+            .map(|location| self.cell(location))
+    }
+}
+
+impl<'a, G: Grid + ?Sized> ExactSizeIterator for CellsWithLocations<'a, G> {}
+impl<'a, G: Grid + ?Sized> FusedIterator for CellsWithLocations<'a, G> {}
+
+/// An iterator over all the cells of a grid, in row-major order. See
+/// [`Grid`]`::`[`cells`][Grid::cells] for details.
+#[derive(Debug)]
+pub struct Cells<'a, G: Grid + ?Sized> {
+    inner: CellsWithLocations<'a, G>,
+}
+
+// Custom clone implementation, because Cells is `Clone` even if G is not
+impl<'a, G: Grid + ?Sized> Clone for Cells<'a, G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized> Iterator for Cells<'a, G> {
+    type Item = &'a G::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, cell)| cell)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|(_, cell)| cell)
+    }
+}
+
+impl<'a, G: Grid + ?Sized> DoubleEndedIterator for Cells<'a, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, cell)| cell)
+    }
+}
+
+impl<'a, G: Grid + ?Sized> ExactSizeIterator for Cells<'a, G> {}
+impl<'a, G: Grid + ?Sized> FusedIterator for Cells<'a, G> {}
+
+/// A wrapper around a grid, allowing it to be printed via [`Display`]. See
+/// [`Grid`]`::`[`display_with`][Grid::display_with] for details.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayAdapter<T, F> {
+    func: F,
+    grid: T,
+}
+
+impl<T, F, R> Display for DisplayAdapter<T, F>
+where
+    T: Grid,
+    F: Fn(&T::Item) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let func = &self.func;
+
+        self.grid.rows().iter().try_for_each(move |row| {
+            row.iter().map(func).try_for_each(|cell| cell.fmt(f))?;
+            f.write_char('\n')
+        })
+    }
+}
+
+/// A wrapper around a grid, allowing it to be printed via [`Display`] with
+/// aligned columns. See [`Grid`]`::`[`display_aligned`][Grid::display_aligned]
+/// for details.
+#[derive(Debug, Copy, Clone)]
+pub struct AlignedDisplayAdapter<T> {
+    grid: T,
+}
+
+/// A `fmt::Write` implementation that discards its input, measuring only its
+/// width in `char`s
+#[derive(Debug, Default)]
+struct WidthCounter {
+    width: usize,
+}
+
+impl Write for WidthCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.width += s.chars().count();
+        Ok(())
+    }
+}
+
+fn rendered_width(value: &impl Display) -> usize {
+    let mut counter = WidthCounter::default();
+    // WidthCounter never returns an error, so this can only fail if the
+    // Display implementation itself fails, in which case the actual print
+    // will fail too.
+    let _ = write!(counter, "{}", value);
+    counter.width
+}
+
+impl<T> Display for AlignedDisplayAdapter<T>
+where
+    T: Grid,
+    T::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let width = self
+            .grid
+            .rows()
+            .iter()
+            .flat_map(|row| row.iter().map(rendered_width))
+            .max()
+            .unwrap_or(0);
+
+        self.grid.rows().iter().try_for_each(move |row| {
+            row.iter().enumerate().try_for_each(|(index, cell)| {
+                if index > 0 {
+                    f.write_char(' ')?;
+                }
+
+                (rendered_width(cell)..width).try_for_each(|_| f.write_char(' '))?;
+                cell.fmt(f)
+            })?;
+            f.write_char('\n')
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::BoundsError;
+    use crate::prelude::*;
+    use crate::range::{ColumnRangeError, RangeError, RowRangeError};
+
+    // A stack-allocated grid with a fixed size of three rows by two columns.
+    // The root of this grid is (-1, 0), which means that the valid rows are
+    // [-1, 0, 1] and the valid columns are [0, 1]
+    #[derive(Debug, Eq, PartialEq)]
+    struct ThreeByTwo<T> {
+        rows: [[T; 2]; 3],
+    }
+
+    impl<T> GridBounds for ThreeByTwo<T> {
         fn dimensions(&self) -> Vector {
             Vector::new(3, 2)
         }
@@ -555,6 +1988,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_any_cells_short_circuit() {
+        let mut checked = 0;
+        assert!(!TEST_GRID.all_cells(|&cell| {
+            checked += 1;
+            cell < 3
+        }));
+        assert_eq!(checked, 3);
+
+        let mut checked = 0;
+        assert!(TEST_GRID.any_cell(|&cell| {
+            checked += 1;
+            cell == 4
+        }));
+        assert_eq!(checked, 4);
+    }
+
+    #[test]
+    fn test_adjacent_pairs() {
+        // 3 rows of 1 horizontal pair, and 2 columns of 2 vertical pairs
+        assert_eq!(TEST_GRID.adjacent_pairs().count(), 7);
+
+        for ((first, &first_cell), (second, &second_cell)) in TEST_GRID.adjacent_pairs() {
+            assert_eq!((second - first).manhattan_length(), 1);
+            assert!(second.row >= first.row && second.column >= first.column);
+            assert_eq!(TEST_GRID.get(first), Ok(&first_cell));
+            assert_eq!(TEST_GRID.get(second), Ok(&second_cell));
+        }
+
+        let mut pairs = TEST_GRID.adjacent_pairs();
+        pairs.by_ref().for_each(drop);
+        assert!(pairs.next().is_none());
+    }
+
     /*
     // Set of view and iterator tests that test the row, column, and generic
     // versions of all the relevant methods.
@@ -1,3 +1,5 @@
+use core::ptr;
+
 use crate::grid::bounds::BoundsError;
 use crate::grid::view::Grid;
 use crate::location::{Location, LocationLike};
@@ -24,6 +26,52 @@ pub trait GridMut: Grid {
         self.check_location(location)
             .map(move |loc| unsafe { self.get_unchecked_mut(loc) })
     }
+
+    /// Swap the values at locations `a` and `b`. Returns an error if either
+    /// location is out of bounds, in which case the grid is unchanged. Swapping
+    /// a location with itself does nothing. The values are moved, not cloned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// # mod doctest_grids { include!("doctest_grids.rs"); }
+    /// # use doctest_grids::VecGrid;
+    ///
+    /// let mut grid = VecGrid::new_row_major((Rows(2), Columns(2)), vec![
+    ///     String::from("a"), String::from("b"),
+    ///     String::from("c"), String::from("d"),
+    /// ]).unwrap();
+    ///
+    /// grid.swap((0, 0), (1, 1)).unwrap();
+    /// assert_eq!(grid[(0, 0)], "d");
+    /// assert_eq!(grid[(1, 1)], "a");
+    ///
+    /// assert!(grid.swap((0, 1), (2, 0)).is_err());
+    /// assert_eq!(grid[(0, 1)], "b");
+    /// ```
+    fn swap(&mut self, a: impl LocationLike, b: impl LocationLike) -> Result<(), BoundsError> {
+        let a = self.check_location(a)?;
+        let b = self.check_location(b)?;
+
+        if a != b {
+            let grid: *mut Self = self;
+
+            // Safety: both locations were just bounds checked, and they're
+            // distinct, so the two pointers refer to different cells. `a` is
+            // fetched both before and after `b`, so that grids which create
+            // cells on demand (like `SparseGrid`) can't move it while
+            // creating `b`.
+            unsafe {
+                let _ = (*grid).get_unchecked_mut(a);
+                let b: *mut Self::Item = (*grid).get_unchecked_mut(b);
+                let a: *mut Self::Item = (*grid).get_unchecked_mut(a);
+                ptr::swap(a, b);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<G: GridMut> GridMut for &mut G {
@@ -36,9 +84,104 @@ impl<G: GridMut> GridMut for &mut G {
     fn get_mut(&mut self, location: impl LocationLike) -> Result<&mut Self::Item, BoundsError> {
         G::get_mut(self, location)
     }
+
+    #[inline]
+    fn swap(&mut self, a: impl LocationLike, b: impl LocationLike) -> Result<(), BoundsError> {
+        G::swap(self, a, b)
+    }
 }
 
 // TODO: mutable views, iterators. Feature parity with `Grid`
 // TODO: modify this trait to support extra behavior when references are dropped
 // (for instance, to allow clearing sparse grids). This will need to wait for
 // HKTs or GATs
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::view_mut::*;
+    use crate::prelude::*;
+    use crate::range::RangeError;
+
+    /// A tile with no `Default`, like the cells of a typical sliding puzzle
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Blank,
+        Number(u8),
+    }
+
+    /// A 2x2 grid in row-major order.
+    #[derive(Debug)]
+    struct SimpleGrid<T> {
+        cells: [T; 4],
+    }
+
+    impl<T> SimpleGrid<T> {
+        fn index_of(loc: Location) -> usize {
+            (loc.row.0 * 2 + loc.column.0) as usize
+        }
+    }
+
+    impl<T> GridBounds for SimpleGrid<T> {
+        fn dimensions(&self) -> Vector {
+            Vector::new(2, 2)
+        }
+
+        fn root(&self) -> Location {
+            Location::zero()
+        }
+    }
+
+    impl<T> Grid for SimpleGrid<T> {
+        type Item = T;
+
+        unsafe fn get_unchecked(&self, location: Location) -> &T {
+            self.cells.get_unchecked(Self::index_of(location))
+        }
+    }
+
+    impl<T> GridMut for SimpleGrid<T> {
+        unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut T {
+            self.cells.get_unchecked_mut(Self::index_of(location))
+        }
+    }
+
+    #[test]
+    fn test_swap() {
+        use Tile::*;
+
+        let mut grid = SimpleGrid {
+            cells: [Number(1), Number(2), Number(3), Blank],
+        };
+
+        assert_eq!(grid.swap((0, 0), (1, 1)), Ok(()));
+        assert_eq!(grid.cells, [Blank, Number(2), Number(3), Number(1)]);
+
+        assert_eq!(grid.swap((0, 1), (0, 1)), Ok(()));
+        assert_eq!(grid.cells, [Blank, Number(2), Number(3), Number(1)]);
+
+        assert_eq!(
+            grid.swap((2, 0), (0, 0)),
+            Err(BoundsError::Row(RangeError::TooHigh(Row(2))))
+        );
+        assert_eq!(
+            grid.swap((0, 0), (0, -1)),
+            Err(BoundsError::Column(RangeError::TooLow(Column(0))))
+        );
+        assert_eq!(grid.cells, [Blank, Number(2), Number(3), Number(1)]);
+    }
+
+    #[test]
+    fn test_swap_through_reference() {
+        fn swap_corners<G: GridMut>(mut grid: G) {
+            assert_eq!(grid.swap((0, 1), (1, 0)), Ok(()));
+            assert!(grid.swap((0, 1), (5, 5)).is_err());
+        }
+
+        let mut grid = SimpleGrid {
+            cells: ["a", "b", "c", "d"],
+        };
+
+        swap_corners(&mut grid);
+        assert_eq!(grid.cells, ["a", "c", "b", "d"]);
+    }
+}
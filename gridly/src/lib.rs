@@ -1,5 +1,11 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod direction;
 pub mod grid;
 pub mod location;
@@ -65,16 +71,20 @@ pub mod prelude {
 
     #[doc(inline)]
     pub use crate::location::{
-        Column, Component as LocationComponent, Location, LocationLike, Row,
+        Column, ColumnOrdered, ColumnOrderedLocation, Component as LocationComponent, Location,
+        LocationLike, Ordered, Row, RowOrdered, RowOrderedLocation,
     };
 
     #[doc(inline)]
-    pub use crate::range::{ColumnRange, ColumnRangeError, LocationRange, RowRange, RowRangeError};
+    pub use crate::range::{
+        rectangle, rectangle_column_major, ColumnRange, ColumnRangeError, CrossRange,
+        LocationRange, RowRange, RowRangeError,
+    };
 
     #[doc(inline)]
     pub use crate::vector::{
         Columns, Component as VectorComponent, Rows, Vector, VectorLike, DIAGONAL_ADJACENCIES,
-        ORTHOGONAL_ADJACENCIES, TOUCHING_ADJACENCIES,
+        KNIGHT_MOVES, ORTHOGONAL_ADJACENCIES, TOUCHING_ADJACENCIES,
     };
 
     #[doc(inline)]
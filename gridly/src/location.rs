@@ -2,14 +2,19 @@
 //! as well as associated types and traits.
 
 use core::cmp::{Ordering, PartialOrd};
+use core::convert::TryFrom;
 use core::fmt::Debug;
 use core::hash::Hash;
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign};
 
 use crate::direction::Direction;
 use crate::range::{ComponentRange, LocationRange};
-use crate::vector::{Columns, Component as VecComponent, Rows, Vector, VectorLike};
+use crate::vector::{
+    Columns, Component as VecComponent, Rows, Vector, VectorLike, DIAGONAL_ADJACENCIES,
+    ORTHOGONAL_ADJACENCIES, TOUCHING_ADJACENCIES,
+};
 
 // TODO: add additional implied traits?
 // TODO: docstrings
@@ -88,6 +93,23 @@ pub trait Component: Sized + From<isize> + Copy + Debug + Ord + Eq + Hash + Defa
     #[must_use]
     fn value(self) -> isize;
 
+    /// Convert this component into the equivalent distance; for instance,
+    /// convert a [`Row`] into [`Rows`]. This is the distance from index 0 to
+    /// this component. This is the inverse of
+    /// [`as_point`][VecComponent::as_point].
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Row(5).as_distance(), Rows(5));
+    /// assert_eq!(Column(-2).as_distance(), Columns(-2));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn as_distance(self) -> Self::Distance {
+        self.value().into()
+    }
+
     /// Add a distance to this component. This method is provided because we can't
     /// require a trait bound on `Add` for `Component`, but in general just using
     /// `+` is preferable.
@@ -100,6 +122,64 @@ pub trait Component: Sized + From<isize> + Copy + Debug + Ord + Eq + Hash + Defa
     #[must_use]
     fn add_distance(self, amount: impl Into<Self::Distance>) -> Self;
 
+    /// Add a distance to this component, returning `None` if the addition
+    /// overflows.
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Row(4).checked_add(Rows(5)), Some(Row(9)));
+    /// assert_eq!(Column(isize::MAX).checked_add(Columns(1)), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn checked_add(self, distance: impl Into<Self::Distance>) -> Option<Self> {
+        self.value()
+            .checked_add(distance.into().value())
+            .map(Self::from)
+    }
+
+    /// Subtract a distance from this component, returning `None` if the
+    /// subtraction overflows.
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Row(4).checked_sub(Rows(5)), Some(Row(-1)));
+    /// assert_eq!(Column(isize::MIN).checked_sub(Columns(1)), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn checked_sub(self, distance: impl Into<Self::Distance>) -> Option<Self> {
+        self.value()
+            .checked_sub(distance.into().value())
+            .map(Self::from)
+    }
+
+    /// Find the midpoint between this component and `other`, rounding down
+    /// (towards negative infinity). This never overflows, even for components
+    /// near the limits of `isize`, which makes it suitable for binary searches
+    /// over rows or columns.
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Row(2).midpoint(Row(8)), Row(5));
+    /// assert_eq!(Row(8).midpoint(Row(2)), Row(5));
+    /// assert_eq!(Column(-3).midpoint(Column(0)), Column(-2));
+    /// assert_eq!(Row(isize::MAX - 2).midpoint(Row(isize::MAX)), Row(isize::MAX - 1));
+    /// assert_eq!(Row(isize::MIN).midpoint(Row(isize::MAX)), Row(-1));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn midpoint(self, other: Self) -> Self {
+        let a = self.value();
+        let b = other.value();
+
+        // Overflow-free floor((a + b) / 2)
+        ((a & b) + ((a ^ b) >> 1)).into()
+    }
+
     /// Find the distance between two components, using this component as the origin
     ///
     /// ```
@@ -379,6 +459,28 @@ macro_rules! make_component {
                 assert_eq!(base, $Name(-1));
             }
 
+            #[test]
+            fn test_checked_add() {
+                assert_eq!($Name(3).checked_add($Distance(4)), Some($Name(7)));
+                assert_eq!(
+                    $Name(isize::MAX - 1).checked_add($Distance(1)),
+                    Some($Name(isize::MAX))
+                );
+                assert_eq!($Name(isize::MAX).checked_add($Distance(1)), None);
+                assert_eq!($Name(isize::MIN).checked_add($Distance(-1)), None);
+            }
+
+            #[test]
+            fn test_checked_sub() {
+                assert_eq!($Name(3).checked_sub($Distance(4)), Some($Name(-1)));
+                assert_eq!(
+                    $Name(isize::MIN + 1).checked_sub($Distance(1)),
+                    Some($Name(isize::MIN))
+                );
+                assert_eq!($Name(isize::MIN).checked_sub($Distance(1)), None);
+                assert_eq!($Name(isize::MAX).checked_sub($Distance(-1)), None);
+            }
+
             #[test]
             fn test_sub_self() {
                 let origin = $Name(2);
@@ -427,6 +529,61 @@ impl Location {
             column: Column(0),
         }
     }
+
+    /// Get the location one step from the origin, `(0, 0)`, in the given
+    /// `direction`. This is the same as `Location::from(direction)`, and is
+    /// useful for building small tables of fixed offsets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Location::origin_step(Up), (-1, 0));
+    /// assert_eq!(Location::origin_step(Right), (0, 1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn origin_step(direction: Direction) -> Self {
+        Location::zero() + direction
+    }
+
+    /// Create a new location from unsigned `row` and `column` indexes, such
+    /// as slice indexes or UI coordinates. Returns `None` if either index
+    /// doesn't fit in an `isize`. See [`to_usize`][LocationLike::to_usize] for
+    /// the reverse conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Location::try_from_usize(3, 4), Some(Location::new(3, 4)));
+    /// assert_eq!(Location::try_from_usize(usize::MAX, 4), None);
+    /// assert_eq!(Location::try_from_usize(3, usize::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_from_usize(row: usize, column: usize) -> Option<Self> {
+        Some(Location {
+            row: Row(isize::try_from(row).ok()?),
+            column: Column(isize::try_from(column).ok()?),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_try_from_usize_boundaries() {
+    let max = isize::MAX as usize;
+
+    assert_eq!(
+        Location::try_from_usize(max, max),
+        Some(Location::new(isize::MAX, isize::MAX))
+    );
+    assert_eq!(Location::try_from_usize(max + 1, 0), None);
+    assert_eq!(Location::try_from_usize(0, max + 1), None);
+    assert_eq!(Location::try_from_usize(0, 0), Some(Location::zero()));
 }
 
 /// This trait covers structs that act like a [`Location`], such as tuples.
@@ -448,6 +605,31 @@ pub trait LocationLike: Sized {
         }
     }
 
+    /// Convert this object into its canonical [`Location`] form. Other
+    /// location-like types, such as `(isize, isize)` tuples, compare equal to
+    /// their equivalent `Location`, but they don't hash the same way, so a
+    /// `HashMap` or `HashSet` that mixes key types will silently fail to find
+    /// entries. Always key hashed collections on `Location`, and use this
+    /// method to normalize keys before inserting or looking them up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut map: HashMap<Location, char> = HashMap::new();
+    /// map.insert((1, 2).canonical(), 'a');
+    ///
+    /// assert_eq!(map.get(&Location::new(1, 2)), Some(&'a'));
+    /// assert_eq!(map.get(&(Row(1), Column(2)).canonical()), Some(&'a'));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn canonical(self) -> Location {
+        self.as_location()
+    }
+
     /// Get either the row or column of a location. This method is useful in
     /// code that is generic over the Row or Column.
     #[inline]
@@ -566,6 +748,145 @@ pub trait LocationLike: Sized {
         self.add(direction.unit_vec())
     }
 
+    /// Return the location that is `distance` away in the given `direction`,
+    /// or `None` if computing it would overflow. This is the checked version
+    /// of [`relative`][LocationLike::relative].
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// assert_eq!(Location::zero().checked_relative(Up, 4), Some(L(-4, 0)));
+    /// assert_eq!(Location::zero().checked_relative(Right, 1), Some(L(0, 1)));
+    /// assert_eq!(L(0, isize::MAX).checked_relative(Right, 1), None);
+    /// assert_eq!(L(isize::MIN, 0).checked_relative(Up, 1), None);
+    /// assert_eq!(L(10, 10).checked_relative(Down, isize::MIN), Some(L(isize::MIN + 10, 10)));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn checked_relative(&self, direction: Direction, distance: isize) -> Option<Location> {
+        let location = self.as_location();
+
+        // Subtract rather than negating `distance`, so that a `distance` of
+        // `isize::MIN` works when the result is in range.
+        Some(match direction {
+            Direction::Up => Location {
+                row: Row(location.row.0.checked_sub(distance)?),
+                column: location.column,
+            },
+            Direction::Down => Location {
+                row: Row(location.row.0.checked_add(distance)?),
+                column: location.column,
+            },
+            Direction::Left => Location {
+                row: location.row,
+                column: Column(location.column.0.checked_sub(distance)?),
+            },
+            Direction::Right => Location {
+                row: location.row,
+                column: Column(location.column.0.checked_add(distance)?),
+            },
+        })
+    }
+
+    /// Return the location that is 1 away in the given `direction`, or `None`
+    /// if computing it would overflow. This is the checked version of
+    /// [`step`][LocationLike::step].
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// let base = Location::new(2, 4);
+    /// assert_eq!(base.checked_step(Up), Some(L(1, 4)));
+    /// assert_eq!(base.checked_step(Left), Some(L(2, 3)));
+    ///
+    /// let corner = Location::new(isize::MAX, isize::MIN);
+    /// assert_eq!(corner.checked_step(Down), None);
+    /// assert_eq!(corner.checked_step(Left), None);
+    /// assert_eq!(corner.checked_step(Up), Some(L(isize::MAX - 1, isize::MIN)));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn checked_step(&self, direction: Direction) -> Option<Location> {
+        self.checked_relative(direction, 1)
+    }
+
+    /// Return the component-wise absolute difference between this location and
+    /// `other`; that is, the number of rows and the number of columns between
+    /// the two locations, regardless of their relative positions. The
+    /// [`manhattan_length`][VectorLike::manhattan_length] of the result is the
+    /// manhattan distance between the locations.
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// assert_eq!(L(1, 8).abs_diff(L(4, 2)), V(3, 6));
+    /// assert_eq!(L(4, 2).abs_diff(L(1, 8)), V(3, 6));
+    /// assert_eq!(L(-2, -2).abs_diff(L(-2, 5)).manhattan_length(), 7);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn abs_diff(&self, other: impl LocationLike) -> Vector {
+        (self.as_location() - other.as_location()).abs()
+    }
+
+    /// Get the unit step from this location toward `target`; that is, the
+    /// [`signum`][VectorLike::signum] of the vector from this location to
+    /// `target`. Each component of the step is -1, 0, or 1, so the step is
+    /// orthogonal or diagonal. If the two locations lie on the same row,
+    /// column, or diagonal, repeatedly adding the step walks from this
+    /// location to `target`. If they're equal, the step is zero.
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// assert_eq!(L(0, 0).step_toward(L(3, 3)), V(1, 1));
+    /// assert_eq!(L(5, 2).step_toward(L(1, 2)), V(-1, 0));
+    /// assert_eq!(L(5, 2).step_toward(L(5, 2)), V(0, 0));
+    ///
+    /// let target = L(4, -1);
+    /// let mut location = L(1, 2);
+    /// let mut path = vec![location];
+    ///
+    /// while location != target {
+    ///     location += location.step_toward(target);
+    ///     path.push(location);
+    /// }
+    ///
+    /// assert_eq!(path, [L(1, 2), L(2, 1), L(3, 0), L(4, -1)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn step_toward(&self, target: impl LocationLike) -> Vector {
+        (target.as_location() - self.as_location()).signum()
+    }
+
+    /// Convert this location to a pair of unsigned `(row, column)` indexes,
+    /// such as for use as slice indexes. Returns `None` if either component is
+    /// negative. See [`Location::try_from_usize`] for the reverse conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// assert_eq!(L(3, 4).to_usize(), Some((3, 4)));
+    /// assert_eq!(L(-1, 4).to_usize(), None);
+    /// assert_eq!(L(3, -4).to_usize(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_usize(&self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row().0).ok()?,
+            usize::try_from(self.column().0).ok()?,
+        ))
+    }
+
     /// Swap the row and colimn of this Location
     ///
     /// Example:
@@ -645,6 +966,40 @@ pub trait LocationLike: Sized {
         self.order_by()
     }
 
+    /// Get a row-ordered copy of this location, suitable for use as a key in
+    /// ordered collections like [`BTreeMap`][alloc_btree]. [`Location`] itself
+    /// only has a partial ordering, so it can't be used as a key directly;
+    /// this is shorthand for `self.as_location().row_ordered()`. Row-major
+    /// order is the default because it matches the usual reading order of a
+    /// grid; use [`column_ordered`][LocationLike::column_ordered] for
+    /// column-major keys.
+    ///
+    /// [alloc_btree]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut map: BTreeMap<RowOrderedLocation, char> = BTreeMap::new();
+    ///
+    /// map.insert(Location::new(1, 0).key(), 'c');
+    /// map.insert(Location::new(0, 5).key(), 'b');
+    /// map.insert(Location::new(0, -3).key(), 'a');
+    ///
+    /// let locations: Vec<Location> = map.keys().map(|&key| key.into()).collect();
+    /// assert_eq!(locations, [Location::new(0, -3), Location::new(0, 5), Location::new(1, 0)]);
+    ///
+    /// let values: String = map.values().collect();
+    /// assert_eq!(values, "abc");
+    /// ```
+    #[inline]
+    #[must_use]
+    fn key(&self) -> RowOrderedLocation {
+        self.as_location().row_ordered()
+    }
+
     /// Create a range, starting at this location, with the given length
     ///
     /// ```
@@ -682,6 +1037,171 @@ pub trait LocationLike: Sized {
     fn range_to<C: Component>(self, end: C) -> LocationRange<C::Converse> {
         LocationRange::bounded(self.get_component(), self.get_component(), end)
     }
+
+    /// Get an iterator over all of the locations at exactly the given
+    /// manhattan `distance` from this location; that is, a diamond-shaped ring
+    /// around this location. For a `distance` of 0, this is just this location,
+    /// and for a negative `distance`, it's empty. Otherwise, the ring contains
+    /// `4 * distance` locations. The locations are produced clockwise,
+    /// starting with the topmost location.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is greater than `isize::MAX / 4`, since the
+    /// length of such a ring can't be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// let ring: Vec<Location> = L(5, 5).manhattan_ring(1).collect();
+    /// assert_eq!(ring, [L(4, 5), L(5, 6), L(6, 5), L(5, 4)]);
+    ///
+    /// assert_eq!(L(5, 5).manhattan_ring(3).len(), 12);
+    /// assert!(L(5, 5)
+    ///     .manhattan_ring(3)
+    ///     .all(|loc| loc.abs_diff(L(5, 5)).manhattan_length() == 3));
+    ///
+    /// assert_eq!(L(5, 5).manhattan_ring(0).collect::<Vec<_>>(), [L(5, 5)]);
+    /// assert_eq!(L(5, 5).manhattan_ring(-1).next(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn manhattan_ring(&self, distance: isize) -> ManhattanRing {
+        ManhattanRing {
+            center: self.as_location(),
+            distance,
+            index: 0,
+            end: match distance {
+                0 => 1,
+                distance if distance < 0 => 0,
+                distance => distance
+                    .checked_mul(4)
+                    .expect("manhattan_ring distance is too large"),
+            },
+        }
+    }
+
+    /// Get an iterator over the locations on the straight line from this
+    /// location to `end`, inclusive of both endpoints, as computed by
+    /// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm).
+    /// Each location in the line is adjacent (possibly diagonally) to the
+    /// previous one, and the line contains one location for each row or
+    /// column it spans, whichever is greater. This is useful for drawing
+    /// lines and for ray-casting visibility checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    ///
+    /// let line: Vec<Location> = L(0, 0).line_to(L(2, 5)).collect();
+    /// assert_eq!(line, [L(0, 0), L(0, 1), L(1, 2), L(1, 3), L(2, 4), L(2, 5)]);
+    ///
+    /// let line: Vec<Location> = L(3, 3).line_to(L(3, 0)).collect();
+    /// assert_eq!(line, [L(3, 3), L(3, 2), L(3, 1), L(3, 0)]);
+    ///
+    /// assert_eq!(L(1, 1).line_to(L(1, 1)).collect::<Vec<_>>(), [L(1, 1)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn line_to(&self, end: impl LocationLike) -> LineIterator {
+        let start = self.as_location();
+        let end = end.as_location();
+        let delta = end - start;
+
+        LineIterator {
+            current: start,
+            step: delta.signum(),
+            columns: delta.columns.0.abs(),
+            rows: -delta.rows.0.abs(),
+            error: delta.columns.0.abs() - delta.rows.0.abs(),
+            remaining: delta.chebyshev_length() + 1,
+        }
+    }
+
+    /// Get the 4 locations orthogonally adjacent to this one: the locations
+    /// directly above, below, left, and right of it. This is a convenience for
+    /// adding each vector in [`ORTHOGONAL_ADJACENCIES`] to this location, and
+    /// like that array, the order of the locations is unspecified and should
+    /// not be relied upon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    /// let adjacent = L(1, 2).orthogonal_neighbors();
+    ///
+    /// assert!(adjacent.contains(&L(0, 2)));
+    /// assert!(adjacent.contains(&L(2, 2)));
+    /// assert!(adjacent.contains(&L(1, 3)));
+    /// assert!(adjacent.contains(&L(1, 1)));
+    /// assert_eq!(adjacent.len(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn orthogonal_neighbors(&self) -> [Location; 4] {
+        let location = self.as_location();
+        ORTHOGONAL_ADJACENCIES.map(|vector| location + vector)
+    }
+
+    /// Get the 4 locations diagonally adjacent to this one. This is a
+    /// convenience for adding each vector in [`DIAGONAL_ADJACENCIES`] to this
+    /// location, and like that array, the order of the locations is
+    /// unspecified and should not be relied upon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    /// let corners = L(1, 2).diagonal_neighbors();
+    ///
+    /// assert!(corners.contains(&L(0, 1)));
+    /// assert!(corners.contains(&L(0, 3)));
+    /// assert!(corners.contains(&L(2, 3)));
+    /// assert!(corners.contains(&L(2, 1)));
+    /// assert_eq!(corners.len(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn diagonal_neighbors(&self) -> [Location; 4] {
+        let location = self.as_location();
+        DIAGONAL_ADJACENCIES.map(|vector| location + vector)
+    }
+
+    /// Get the 8 locations that touch this one, orthogonally or diagonally.
+    /// This is a convenience for adding each vector in
+    /// [`TOUCHING_ADJACENCIES`] to this location, and like that array, the
+    /// order of the locations is unspecified and should not be relied upon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    /// use gridly::shorthand::*;
+    /// let touching = L(1, 2).touching_neighbors();
+    ///
+    /// assert!(touching.contains(&L(0, 1)));
+    /// assert!(touching.contains(&L(0, 3)));
+    /// assert!(touching.contains(&L(2, 3)));
+    /// assert!(touching.contains(&L(2, 1)));
+    /// assert!(touching.contains(&L(0, 2)));
+    /// assert!(touching.contains(&L(2, 2)));
+    /// assert!(touching.contains(&L(1, 3)));
+    /// assert!(touching.contains(&L(1, 1)));
+    /// assert_eq!(touching.len(), 8);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn touching_neighbors(&self) -> [Location; 8] {
+        let location = self.as_location();
+        TOUCHING_ADJACENCIES.map(|vector| location + vector)
+    }
 }
 
 impl LocationLike for Location {
@@ -724,6 +1244,103 @@ impl<T: LocationLike> LocationLike for &T {
     }
 }
 
+/// Convert a [`Direction`] into the location one step from the origin in
+/// that direction. The result is relative to the origin, `(0, 0)`; see
+/// [`Location::origin_step`].
+impl From<Direction> for Location {
+    #[inline]
+    fn from(direction: Direction) -> Self {
+        Location::origin_step(direction)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_from_direction() {
+    use crate::direction::*;
+
+    assert_eq!(Location::from(Up), Location::new(-1, 0));
+    assert_eq!(Location::from(Down), Location::new(1, 0));
+    assert_eq!(Location::from(Left), Location::new(0, -1));
+    assert_eq!(Location::from(Right), Location::new(0, 1));
+
+    for &direction in &EACH_DIRECTION {
+        assert_eq!(Location::origin_step(direction), Location::from(direction));
+        assert_eq!(
+            Location::from(direction) - Location::zero(),
+            direction.unit_vec()
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_to_usize_boundaries() {
+    assert_eq!(Location::new(0, 0).to_usize(), Some((0, 0)));
+    assert_eq!(
+        Location::new(isize::MAX, isize::MAX).to_usize(),
+        Some((isize::MAX as usize, isize::MAX as usize))
+    );
+    assert_eq!(Location::new(-1, 0).to_usize(), None);
+    assert_eq!(Location::new(0, -1).to_usize(), None);
+    assert_eq!(Location::new(isize::MIN, isize::MIN).to_usize(), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_checked_step_overflow() {
+    use crate::direction::*;
+
+    let max = Location::new(isize::MAX, isize::MAX);
+    assert_eq!(max.checked_step(Down), None);
+    assert_eq!(max.checked_step(Right), None);
+    assert_eq!(
+        max.checked_step(Up),
+        Some(Location::new(isize::MAX - 1, isize::MAX))
+    );
+    assert_eq!(
+        max.checked_step(Left),
+        Some(Location::new(isize::MAX, isize::MAX - 1))
+    );
+
+    let min = Location::new(isize::MIN, isize::MIN);
+    assert_eq!(min.checked_step(Up), None);
+    assert_eq!(min.checked_step(Left), None);
+    assert_eq!(
+        min.checked_step(Down),
+        Some(Location::new(isize::MIN + 1, isize::MIN))
+    );
+    assert_eq!(
+        min.checked_step(Right),
+        Some(Location::new(isize::MIN, isize::MIN + 1))
+    );
+
+    let origin = Location::zero();
+    assert_eq!(
+        origin.checked_relative(Down, isize::MAX),
+        Some(Location::new(isize::MAX, 0))
+    );
+    assert_eq!(
+        origin.checked_relative(Up, isize::MAX),
+        Some(Location::new(-isize::MAX, 0))
+    );
+    assert_eq!(origin.checked_relative(Up, isize::MIN), None);
+    assert_eq!(origin.checked_relative(Left, isize::MIN), None);
+    assert_eq!(
+        Location::new(-1, 0).checked_relative(Up, isize::MIN),
+        Some(Location::new(isize::MAX, 0))
+    );
+    assert_eq!(
+        Location::new(0, -1).checked_relative(Left, isize::MIN),
+        Some(Location::new(0, isize::MAX))
+    );
+    assert_eq!(Location::new(1, 0).checked_relative(Down, isize::MAX), None);
+    assert_eq!(
+        Location::new(0, -2).checked_relative(Left, isize::MAX),
+        None
+    );
+}
+
 impl<T: VectorLike> Add<T> for Location {
     type Output = Location;
 
@@ -1045,6 +1662,13 @@ impl<L: LocationLike, M: Component> From<L> for Ordered<L, M> {
     }
 }
 
+impl<M: Component> From<Ordered<Location, M>> for Location {
+    #[inline]
+    fn from(ordered: Ordered<Location, M>) -> Self {
+        ordered.location
+    }
+}
+
 impl<L: LocationLike, M: Component> AsRef<L> for Ordered<L, M> {
     #[inline]
     #[must_use]
@@ -1162,3 +1786,305 @@ pub type RowOrderedLocation = RowOrdered<Location>;
 
 /// Type alias for a [`Location`] ordered by column.
 pub type ColumnOrderedLocation = ColumnOrdered<Location>;
+
+/// Sort a slice of locations in row-major order: first by row, then by
+/// column. [`Location`]'s own [`PartialOrd`] implementation is only a partial
+/// ordering, so locations can't be sorted directly with [`slice::sort`]; this
+/// function sorts them as though they were wrapped in [`RowOrdered`].
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::location::sort_row_major;
+///
+/// let mut locations = [Row(1) + Column(0), Row(0) + Column(2), Row(0) + Column(1)];
+/// sort_row_major(&mut locations);
+///
+/// assert_eq!(locations, [Row(0) + Column(1), Row(0) + Column(2), Row(1) + Column(0)]);
+/// ```
+#[inline]
+pub fn sort_row_major(locations: &mut [Location]) {
+    locations.sort_unstable_by_key(|&location| location.row_ordered())
+}
+
+/// Sort a slice of locations in column-major order: first by column, then by
+/// row. [`Location`]'s own [`PartialOrd`] implementation is only a partial
+/// ordering, so locations can't be sorted directly with [`slice::sort`]; this
+/// function sorts them as though they were wrapped in [`ColumnOrdered`].
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::location::sort_column_major;
+///
+/// let mut locations = [Row(1) + Column(0), Row(0) + Column(2), Row(0) + Column(1)];
+/// sort_column_major(&mut locations);
+///
+/// assert_eq!(locations, [Row(1) + Column(0), Row(0) + Column(1), Row(0) + Column(2)]);
+/// ```
+#[inline]
+pub fn sort_column_major(locations: &mut [Location]) {
+    locations.sort_unstable_by_key(|&location| location.column_ordered())
+}
+
+/// An iterator over the locations at an exact manhattan distance from some
+/// center location. See [`manhattan_ring`][LocationLike::manhattan_ring] for
+/// details.
+#[derive(Debug, Clone)]
+pub struct ManhattanRing {
+    center: Location,
+    distance: isize,
+    index: isize,
+    end: isize,
+}
+
+impl ManhattanRing {
+    fn location_at(&self, index: isize) -> Location {
+        let distance = self.distance;
+
+        if distance == 0 {
+            return self.center;
+        }
+
+        let offset = index % distance;
+
+        // Each side of the ring is a diagonal line from one corner to the
+        // next, going clockwise from the top.
+        let (rows, columns) = match index / distance {
+            0 => (offset - distance, offset),
+            1 => (offset, distance - offset),
+            2 => (distance - offset, -offset),
+            _ => (-offset, offset - distance),
+        };
+
+        self.center + Vector::new(rows, columns)
+    }
+}
+
+impl Iterator for ManhattanRing {
+    type Item = Location;
+
+    #[inline]
+    fn next(&mut self) -> Option<Location> {
+        if self.index < self.end {
+            let location = self.location_at(self.index);
+            self.index += 1;
+            Some(location)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.index) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ManhattanRing {
+    #[inline]
+    fn next_back(&mut self) -> Option<Location> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(self.location_at(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for ManhattanRing {}
+impl FusedIterator for ManhattanRing {}
+
+/// An iterator over the locations on a straight line between two locations.
+/// See [`line_to`][LocationLike::line_to] for details.
+#[derive(Debug, Clone)]
+pub struct LineIterator {
+    current: Location,
+    step: Vector,
+
+    // The absolute column distance and negated absolute row distance of the
+    // whole line, and the accumulated error term, in the usual Bresenham
+    // formulation.
+    columns: isize,
+    rows: isize,
+    error: isize,
+
+    remaining: isize,
+}
+
+impl Iterator for LineIterator {
+    type Item = Location;
+
+    #[inline]
+    fn next(&mut self) -> Option<Location> {
+        if self.remaining <= 0 {
+            return None;
+        }
+
+        let location = self.current;
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            let doubled = self.error * 2;
+
+            if doubled >= self.rows {
+                self.error += self.rows;
+                self.current.column += self.step.columns;
+            }
+
+            if doubled <= self.columns {
+                self.error += self.columns;
+                self.current.row += self.step.rows;
+            }
+        }
+
+        Some(location)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for LineIterator {}
+impl FusedIterator for LineIterator {}
+
+#[cfg(test)]
+#[test]
+fn test_manhattan_ring() {
+    let center = Location::new(-2, 3);
+
+    for distance in 1..6 {
+        let ring = center.manhattan_ring(distance);
+        assert_eq!(ring.len(), distance as usize * 4);
+        assert_eq!(ring.clone().count(), distance as usize * 4);
+
+        for (i, location) in ring.clone().enumerate() {
+            assert_eq!((location - center).manhattan_length(), distance);
+            assert!(!ring.clone().take(i).any(|other| other == location));
+        }
+    }
+
+    let ring = center.manhattan_ring(2);
+    assert!(ring.clone().any(|loc| loc == Location::new(-4, 3)));
+    assert!(ring.clone().any(|loc| loc == Location::new(-1, 4)));
+    assert!(ring.clone().any(|loc| loc == Location::new(-2, 1)));
+    assert!(!ring.clone().any(|loc| loc == Location::new(-3, 3)));
+
+    for i in 0..8 {
+        assert_eq!(ring.clone().nth(i), ring.clone().rev().nth(7 - i));
+    }
+
+    let ring = center.manhattan_ring(isize::MAX / 4);
+    assert_eq!(ring.len(), (isize::MAX / 4) as usize * 4);
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic]
+fn test_manhattan_ring_overflow() {
+    let _ring = Location::zero().manhattan_ring(isize::MAX / 4 + 1);
+}
+
+#[test]
+fn test_sort_row_major() {
+    let mut locations = [
+        Location::new(2, 0),
+        Location::new(0, 3),
+        Location::new(1, 1),
+        Location::new(0, -1),
+        Location::new(2, -5),
+        Location::new(1, 0),
+    ];
+
+    sort_row_major(&mut locations);
+
+    assert_eq!(
+        locations,
+        [
+            Location::new(0, -1),
+            Location::new(0, 3),
+            Location::new(1, 0),
+            Location::new(1, 1),
+            Location::new(2, -5),
+            Location::new(2, 0),
+        ]
+    );
+
+    sort_column_major(&mut locations);
+
+    assert_eq!(
+        locations,
+        [
+            Location::new(2, -5),
+            Location::new(0, -1),
+            Location::new(1, 0),
+            Location::new(2, 0),
+            Location::new(1, 1),
+            Location::new(0, 3),
+        ]
+    );
+}
+
+#[cfg(test)]
+mod line_tests {
+    use super::*;
+
+    fn check_line(start: Location, end: Location, expected: &[(isize, isize)]) {
+        let mut line = start.line_to(end);
+        assert_eq!(line.len(), expected.len());
+
+        for location in expected {
+            assert_eq!(line.next(), Some(location.as_location()));
+        }
+        assert_eq!(line.next(), None);
+        assert_eq!(line.next(), None);
+    }
+
+    #[test]
+    fn test_shallow_line() {
+        let expected = [(0, 0), (0, 1), (1, 2), (1, 3), (2, 4), (2, 5)];
+        check_line(Location::new(0, 0), Location::new(2, 5), &expected);
+
+        let expected = [(2, 5), (2, 4), (1, 3), (1, 2), (0, 1), (0, 0)];
+        check_line(Location::new(2, 5), Location::new(0, 0), &expected);
+    }
+
+    #[test]
+    fn test_steep_line() {
+        let expected = [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)];
+        check_line(Location::new(0, 0), Location::new(5, 2), &expected);
+
+        let expected = [(0, 0), (-1, 0), (-2, -1), (-3, -1), (-4, -2), (-5, -2)];
+        check_line(Location::new(0, 0), Location::new(-5, -2), &expected);
+    }
+
+    #[test]
+    fn test_diagonal_line() {
+        let expected = [(1, 1), (2, 0), (3, -1), (4, -2)];
+        check_line(Location::new(1, 1), Location::new(4, -2), &expected);
+
+        let expected = [(0, 0), (-1, -1), (-2, -2)];
+        check_line(Location::new(0, 0), Location::new(-2, -2), &expected);
+    }
+
+    #[test]
+    fn test_straight_lines() {
+        let expected = [(2, 3), (3, 3), (4, 3)];
+        check_line(Location::new(2, 3), Location::new(4, 3), &expected);
+
+        let expected = [(2, 3), (2, 2), (2, 1)];
+        check_line(Location::new(2, 3), Location::new(2, 1), &expected);
+    }
+
+    #[test]
+    fn test_single_point_line() {
+        check_line(Location::new(7, -3), Location::new(7, -3), &[(7, -3)]);
+    }
+}
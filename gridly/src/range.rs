@@ -7,7 +7,7 @@
 
 use core::cmp::Ordering;
 use core::fmt::{self, Display, Formatter};
-use core::iter::FusedIterator;
+use core::iter::{FusedIterator, Rev};
 use core::marker::PhantomData;
 use core::ops::Range;
 
@@ -156,6 +156,169 @@ impl<C: Component> ComponentRange<C> {
         self.check(loc).is_ok()
     }
 
+    /// Get the offset of `value` from the start of the range, if it's in the
+    /// range. This is the value's position in the range; for instance, it can
+    /// be used to map a row or column to a position in a flat buffer.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::RowRange;
+    /// use gridly::location::Row;
+    /// use gridly::vector::Rows;
+    ///
+    /// let range = RowRange::span(Row(-2), Rows(5));
+    ///
+    /// assert_eq!(range.offset_of(Row(-2)), Some(Rows(0)));
+    /// assert_eq!(range.offset_of(Row(1)), Some(Rows(3)));
+    /// assert_eq!(range.offset_of(Row(3)), None);
+    /// assert_eq!(range.offset_of(Row(-3)), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn offset_of(&self, value: impl Into<C>) -> Option<C::Distance> {
+        self.check(value)
+            .ok()
+            .map(|value| value.distance_from(self.start()))
+    }
+
+    /// Get the center of the range, rounding down (towards `start`) if the
+    /// range has an even size. For an empty range, this is the `start` of the
+    /// range, which is not itself in the range.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::RowRange;
+    /// use gridly::location::Row;
+    /// use gridly::vector::Rows;
+    ///
+    /// assert_eq!(RowRange::span(Row(2), Rows(5)).center(), Row(4));
+    /// assert_eq!(RowRange::span(Row(-3), Rows(4)).center(), Row(-2));
+    /// assert_eq!(RowRange::span(Row(0), Rows(4)).center(), Row(1));
+    /// assert_eq!(RowRange::span(Row(7), Rows(1)).center(), Row(7));
+    /// assert_eq!(RowRange::span(Row(7), Rows(0)).center(), Row(7));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn center(&self) -> C {
+        if self.range.start >= self.range.end {
+            self.start()
+        } else {
+            self.start().midpoint(self.end().add_distance(-1))
+        }
+    }
+
+    /// Clamp a `Row` or `Column` to this range, returning the nearest value
+    /// that is in the range. This is the infallible counterpart to
+    /// [`check`][ComponentRange::check]; values that are too low are clamped
+    /// to the `start` of the range, and values that are too high are clamped
+    /// to the last value in the range. If the range is empty, there are no
+    /// values in the range, so the `start` of the range is returned.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::ColumnRange;
+    /// use gridly::location::Column;
+    /// use gridly::vector::Columns;
+    ///
+    /// let range = ColumnRange::span(Column(3), Columns(5));
+    ///
+    /// assert_eq!(range.clamp(Column(5)), Column(5));
+    /// assert_eq!(range.clamp(Column(-10)), Column(3));
+    /// assert_eq!(range.clamp(Column(8)), Column(7));
+    /// assert_eq!(range.clamp(20), Column(7));
+    ///
+    /// let empty = ColumnRange::span(Column(3), Columns(0));
+    /// assert_eq!(empty.clamp(Column(10)), Column(3));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn clamp(&self, value: impl Into<C>) -> C {
+        if self.range.start >= self.range.end {
+            self.start()
+        } else {
+            match self.check(value) {
+                Ok(value) => value,
+                Err(RangeError::TooLow(min)) => min,
+                Err(RangeError::TooHigh(max)) => max.add_distance(-1),
+            }
+        }
+    }
+
+    /// Check if this range and `other` have any values in common. Ranges are
+    /// half-open, so ranges that touch but don't share any values (like
+    /// `[0, 3)` and `[3, 6)`) don't overlap. Empty ranges never overlap
+    /// anything.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::RowRange;
+    /// use gridly::location::Row;
+    ///
+    /// let range = RowRange::bounded(Row(0), Row(3));
+    ///
+    /// assert!(range.overlaps(&RowRange::bounded(Row(2), Row(5))));
+    /// assert!(range.overlaps(&RowRange::bounded(Row(-5), Row(10))));
+    ///
+    /// // Touching
+    /// assert!(!range.overlaps(&RowRange::bounded(Row(3), Row(6))));
+    /// assert!(!range.overlaps(&RowRange::bounded(Row(-3), Row(0))));
+    ///
+    /// // Disjoint
+    /// assert!(!range.overlaps(&RowRange::bounded(Row(10), Row(12))));
+    ///
+    /// // Empty
+    /// assert!(!range.overlaps(&RowRange::bounded(Row(1), Row(1))));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start().max(other.start()) < self.end().min(other.end())
+    }
+
+    /// Binary search this range for the first value for which `pred` returns
+    /// `false`. The range must be partitioned by `pred`: `pred` must return
+    /// `true` for every value before some point, and `false` for every value
+    /// after it. If `pred` returns `true` for every value in the range, the
+    /// `end` of the range is returned. This is the `Row` and `Column`
+    /// equivalent of [`slice::partition_point`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::RowRange;
+    /// use gridly::location::Row;
+    ///
+    /// let range = RowRange::bounded(Row(0), Row(128));
+    ///
+    /// assert_eq!(range.partition_point(|row| row.0 < 44), Row(44));
+    /// assert_eq!(range.partition_point(|_| true), Row(128));
+    /// assert_eq!(range.partition_point(|_| false), Row(0));
+    ///
+    /// let empty = RowRange::bounded(Row(5), Row(5));
+    /// assert_eq!(empty.partition_point(|_| true), Row(5));
+    /// ```
+    #[must_use]
+    pub fn partition_point(&self, mut pred: impl FnMut(C) -> bool) -> C {
+        let mut low = self.start();
+        let mut high = self.end();
+
+        while low < high {
+            let mid = low.midpoint(high);
+
+            if pred(mid) {
+                low = mid.add_distance(1);
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+
     /// Combine an index range with a converse index to create a [`LocationRange`]
     ///
     /// # Example:
@@ -178,6 +341,37 @@ impl<C: Component> ComponentRange<C> {
     pub fn cross(self, index: C::Converse) -> LocationRange<C::Converse> {
         LocationRange::new(index, self)
     }
+
+    /// Get a reversed version of this range, which iterates from high to low.
+    ///
+    /// This is different from [`Iterator::rev`], which wraps the range in a
+    /// [`Rev`](core::iter::Rev) adapter and therefore loses access to
+    /// `ComponentRange` methods like [`check`][ComponentRange::check] and
+    /// [`cross`][ComponentRange::cross]. The returned [`RevComponentRange`]
+    /// yields the same elements as `.rev()` but retains those methods.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::range::RowRange;
+    /// use gridly::location::{Row, Column};
+    /// use gridly::shorthand::L;
+    ///
+    /// let mut range = RowRange::bounded(Row(3), Row(6)).reversed();
+    ///
+    /// assert_eq!(range.next(), Some(Row(5)));
+    /// assert!(range.in_bounds(Row(3)));
+    ///
+    /// let mut loc_range = range.cross(Column(1));
+    /// assert_eq!(loc_range.next(), Some(L(4, 1)));
+    /// assert_eq!(loc_range.next(), Some(L(3, 1)));
+    /// assert_eq!(loc_range.next(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reversed(self) -> RevComponentRange<C> {
+        RevComponentRange { range: self }
+    }
 }
 
 // TODO: impl RangeBounds for ComponentRange.
@@ -219,9 +413,137 @@ impl<C: Component> ExactSizeIterator for ComponentRange<C> {}
 impl<C: Component> FusedIterator for ComponentRange<C> {}
 // TODO: TrustedLen when stable
 
+/// Iterate over a borrowed range without consuming it. Because a
+/// `ComponentRange` is its own iterator, this simply iterates over a clone of
+/// the range, which is cheap.
+///
+/// # Example:
+///
+/// ```
+/// use gridly::range::RowRange;
+/// use gridly::location::Row;
+///
+/// let range = RowRange::bounded(Row(1), Row(4));
+/// let mut rows = Vec::new();
+///
+/// for row in &range {
+///     rows.push(row);
+/// }
+///
+/// assert_eq!(rows, [Row(1), Row(2), Row(3)]);
+/// assert_eq!(range.len(), 3);
+/// ```
+impl<C: Component> IntoIterator for &ComponentRange<C> {
+    type Item = C;
+    type IntoIter = ComponentRange<C>;
+
+    #[inline]
+    fn into_iter(self) -> ComponentRange<C> {
+        self.clone()
+    }
+}
+
 pub type RowRange = ComponentRange<Row>;
 pub type ColumnRange = ComponentRange<Column>;
 
+/// A [`ComponentRange`] that iterates in reverse, from high to low. Created by
+/// [`ComponentRange::reversed`].
+///
+/// Unlike a plain [`Rev`](core::iter::Rev), this type retains the range
+/// methods of [`ComponentRange`], such as [`check`][RevComponentRange::check]
+/// and [`cross`][RevComponentRange::cross]. Its bounds are still expressed
+/// as a half open `[start..end)` range; only the iteration order is reversed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RevComponentRange<C: Component> {
+    range: ComponentRange<C>,
+}
+
+impl<C: Component> RevComponentRange<C> {
+    /// Get the start (lowest) index of the range. Note that this is the
+    /// *last* index iterated.
+    #[must_use]
+    #[inline]
+    pub fn start(&self) -> C {
+        self.range.start()
+    }
+
+    /// Get the end index of the range, exclusive.
+    #[must_use]
+    #[inline]
+    pub fn end(&self) -> C {
+        self.range.end()
+    }
+
+    /// Get the size of the range
+    #[must_use]
+    #[inline]
+    pub fn size(&self) -> C::Distance {
+        self.range.size()
+    }
+
+    /// Check that a `Row` or `Column` is in bounds for this range. See
+    /// [`ComponentRange::check`] for details.
+    #[inline]
+    pub fn check(&self, idx: impl Into<C>) -> Result<C, RangeError<C>> {
+        self.range.check(idx)
+    }
+
+    /// Check that a `Row` or `Column` is in bounds for this range.
+    #[must_use]
+    #[inline]
+    pub fn in_bounds(&self, loc: impl Into<C>) -> bool {
+        self.range.in_bounds(loc)
+    }
+
+    /// Combine an index range with a converse index to create a reversed
+    /// [`LocationRange`], which iterates locations from high to low.
+    #[inline]
+    pub fn cross(self, index: C::Converse) -> Rev<LocationRange<C::Converse>> {
+        self.range.cross(index).rev()
+    }
+
+    /// Get the forward version of this range, which iterates from low to high.
+    #[must_use]
+    #[inline]
+    pub fn reversed(self) -> ComponentRange<C> {
+        self.range
+    }
+}
+
+impl<C: Component> Iterator for RevComponentRange<C> {
+    type Item = C;
+
+    #[inline]
+    fn next(&mut self) -> Option<C> {
+        self.range.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<C> {
+        self.range.nth_back(n)
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<C> {
+        self.range.next()
+    }
+}
+
+impl<C: Component> DoubleEndedIterator for RevComponentRange<C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<C> {
+        self.range.next()
+    }
+}
+
+impl<C: Component> ExactSizeIterator for RevComponentRange<C> {}
+impl<C: Component> FusedIterator for RevComponentRange<C> {}
+
 /// Error indicating that a Row or Column was out of bounds.
 ///
 /// Note that the bounds expressed in this error are half inclusive; that is,
@@ -514,6 +836,60 @@ impl<C: Component> DoubleEndedIterator for CrossRange<C> {
 impl<C: Component> FusedIterator for CrossRange<C> {}
 impl<C: Component> ExactSizeIterator for CrossRange<C> {}
 
+/// Get an iterator over every location in the rectangle spanned by a range of
+/// `rows` and a range of `columns`, in row-major order. The rectangle isn't
+/// tied to any grid, so this is useful for iterating over arbitrary regions
+/// of locations. See [`rectangle_column_major`] for a column-major version.
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::shorthand::*;
+///
+/// let locations: Vec<Location> = rectangle(
+///     RowRange::span(Row(1), Rows(2)),
+///     ColumnRange::span(Column(-1), Columns(3)),
+/// ).collect();
+///
+/// assert_eq!(locations, [
+///     L(1, -1), L(1, 0), L(1, 1),
+///     L(2, -1), L(2, 0), L(2, 1),
+/// ]);
+/// ```
+#[must_use]
+#[inline]
+pub fn rectangle(rows: RowRange, columns: ColumnRange) -> CrossRange<Row> {
+    CrossRange::new(rows, columns)
+}
+
+/// Get an iterator over every location in the rectangle spanned by a range of
+/// `rows` and a range of `columns`, in column-major order. See [`rectangle`]
+/// for a row-major version.
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::shorthand::*;
+///
+/// let locations: Vec<Location> = rectangle_column_major(
+///     RowRange::span(Row(1), Rows(2)),
+///     ColumnRange::span(Column(-1), Columns(3)),
+/// ).collect();
+///
+/// assert_eq!(locations, [
+///     L(1, -1), L(2, -1),
+///     L(1, 0), L(2, 0),
+///     L(1, 1), L(2, 1),
+/// ]);
+/// ```
+#[must_use]
+#[inline]
+pub fn rectangle_column_major(rows: RowRange, columns: ColumnRange) -> CrossRange<Column> {
+    CrossRange::new(columns, rows)
+}
+
 #[test]
 fn test_cross_range() {
     use crate::vector::{Columns, Rows};
@@ -611,3 +987,55 @@ fn test_cross_range_converge() {
     assert_eq!(converging_cross_range.next_back(), None);
     assert_eq!(converging_cross_range.size_hint(), (0, Some(0)));
 }
+
+#[test]
+fn test_reversed_range() {
+    use crate::vector::Rows;
+
+    let range = RowRange::span(Row(-2), Rows(5));
+    let reversed = range.clone().reversed();
+
+    assert!(reversed.clone().eq(range.clone().rev()));
+    assert!(reversed.clone().rev().eq(range.clone()));
+    assert_eq!(reversed.len(), 5);
+    assert_eq!(reversed.clone().last(), Some(Row(-2)));
+    assert_eq!(reversed.clone().nth(1), Some(Row(1)));
+    assert_eq!(reversed.check(Row(3)), Err(RangeError::TooHigh(Row(3))));
+
+    assert!(reversed
+        .clone()
+        .cross(Column(4))
+        .eq(range.clone().rev().map(|row| row + Column(4))));
+
+    assert_eq!(reversed.reversed(), range);
+}
+
+#[test]
+fn test_center_extreme() {
+    let range = RowRange::bounded(Row(isize::MIN), Row(isize::MAX));
+    assert_eq!(range.center(), Row(-1));
+
+    let range = RowRange::bounded(Row(isize::MAX - 4), Row(isize::MAX));
+    assert_eq!(range.center(), Row(isize::MAX - 3));
+}
+
+#[test]
+fn test_borrowed_range_iteration() {
+    use crate::vector::Columns;
+
+    let range = ColumnRange::span(Column(-1), Columns(3));
+    let mut count = 0;
+
+    for column in &range {
+        assert!(range.in_bounds(column));
+        count += 1;
+    }
+
+    for (column, expected) in (&range).into_iter().zip(-1..) {
+        assert_eq!(column, Column(expected));
+        count += 1;
+    }
+
+    assert_eq!(count, 6);
+    assert_eq!(range, ColumnRange::span(Column(-1), Columns(3)));
+}
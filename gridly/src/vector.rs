@@ -108,6 +108,24 @@ pub trait Component:
     /// ```
     #[must_use]
     fn transpose(self) -> Self::Converse;
+
+    /// Convert this distance into the equivalent location component; for
+    /// instance, convert [`Rows`] into a [`Row`]. This is the inverse of
+    /// [`as_distance`][LocComponent::as_distance].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Rows(5).as_point(), Row(5));
+    /// assert_eq!(Columns(-2).as_point(), Column(-2));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn as_point(self) -> Self::Point {
+        self.value().into()
+    }
 }
 
 // TODO: add docstrings to these. Perhaps refer back to Component
@@ -627,6 +645,48 @@ pub trait VectorLike: Sized {
         rows.checked_add(columns)
     }
 
+    /// Return the chebyshev length of the vector. The chebyshev length of a
+    /// vector is the largest of the absolute values of its components; it's
+    /// the number of moves a chess king would need to travel the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// let vec = Vector::new(-8, 3);
+    /// assert_eq!(vec.chebyshev_length(), 8);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn chebyshev_length(&self) -> isize {
+        self.rows().0.abs().max(self.columns().0.abs())
+    }
+
+    /// Return the euclidean length of the vector. The euclidean length of a
+    /// vector is the straight-line distance it covers: the square root of the
+    /// sum of the squares of its components.
+    ///
+    /// Requires the `std` or `libm` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// let vec = Vector::new(-3, 4);
+    /// assert_eq!(vec.euclidean_length(), 5.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    #[must_use]
+    fn euclidean_length(&self) -> f64 {
+        let rows = self.rows().0 as f64;
+        let columns = self.columns().0 as f64;
+
+        sqrt(rows * rows + columns * columns)
+    }
+
     /// Return a new vector, rotated 90 degrees clockwise.
     ///
     /// # Example
@@ -751,6 +811,54 @@ pub trait VectorLike: Sized {
         }
     }
 
+    /// Reflect this vector across a horizontal line (such as a row), as
+    /// though it bounced off a horizontal wall. The rows component is negated,
+    /// and the columns component is unchanged. This is the vector equivalent
+    /// of [`Direction::reflect_vertical`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    /// use gridly::direction::*;
+    ///
+    /// let vec = Vector::new(2, 8);
+    /// assert_eq!(vec.reflect_across_row(), Vector::new(-2, 8));
+    /// assert_eq!(Up.as_vector().reflect_across_row(), Down.as_vector());
+    /// ```
+    #[inline]
+    #[must_use]
+    fn reflect_across_row(&self) -> Vector {
+        Vector {
+            rows: -self.rows(),
+            columns: self.columns(),
+        }
+    }
+
+    /// Reflect this vector across a vertical line (such as a column), as
+    /// though it bounced off a vertical wall. The columns component is
+    /// negated, and the rows component is unchanged. This is the vector
+    /// equivalent of [`Direction::reflect_horizontal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    /// use gridly::direction::*;
+    ///
+    /// let vec = Vector::new(2, 8);
+    /// assert_eq!(vec.reflect_across_column(), Vector::new(2, -8));
+    /// assert_eq!(Up.as_vector().reflect_across_column(), Up.as_vector());
+    /// ```
+    #[inline]
+    #[must_use]
+    fn reflect_across_column(&self) -> Vector {
+        Vector {
+            rows: self.rows(),
+            columns: -self.columns(),
+        }
+    }
+
     /// If the vector is pointing in an orthogonal direction, return
     /// that direction
     ///
@@ -779,6 +887,250 @@ pub trait VectorLike: Sized {
             _ => None,
         }
     }
+
+    /// Return a vector containing the sign (`-1`, `0`, or `1`) of each
+    /// component of this vector. For a vector pointing from one location
+    /// to another, this is the single step (possibly diagonal) that moves
+    /// toward the target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// assert_eq!(Vector::new(5, -3).signum(), (1, -1));
+    /// assert_eq!(Vector::new(0, 4).signum(), (0, 1));
+    /// assert_eq!(Vector::zero().signum(), (0, 0));
+    /// assert_eq!(Rows(-10).signum(), (-1, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn signum(&self) -> Vector {
+        Vector {
+            rows: Rows(self.rows().0.signum()),
+            columns: Columns(self.columns().0.signum()),
+        }
+    }
+
+    /// Return a vector containing the absolute value of each component of
+    /// this vector. Like [`isize::abs`], if either component is `isize::MIN`,
+    /// this panics in debug builds and wraps (leaving that component as
+    /// `isize::MIN`) in release builds. See
+    /// [`checked_abs`][VectorLike::checked_abs] for a version that doesn't
+    /// overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// assert_eq!(Vector::new(-3, 4).abs(), (3, 4));
+    /// assert_eq!(Vector::new(5, -9).abs(), (5, 9));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn abs(&self) -> Vector {
+        Vector {
+            rows: Rows(self.rows().0.abs()),
+            columns: Columns(self.columns().0.abs()),
+        }
+    }
+
+    /// Return a vector containing the absolute value of each component of
+    /// this vector, or `None` if there are any overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// assert_eq!(Vector::new(-3, 4).checked_abs(), Some(Vector::new(3, 4)));
+    /// assert_eq!(Vector::new(isize::MIN, 4).checked_abs(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn checked_abs(&self) -> Option<Vector> {
+        Some(Vector {
+            rows: Rows(self.rows().0.checked_abs()?),
+            columns: Columns(self.columns().0.checked_abs()?),
+        })
+    }
+
+    /// Compute the component-wise euclidean remainder of this vector by
+    /// `modulus`; that is, `rows.rem_euclid(modulus.rows)` and likewise for
+    /// the columns. Unlike `%`, the result is never negative, which makes this
+    /// suitable for wrapping locations around the edges of a grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either component of `modulus` is zero, like
+    /// [`isize::rem_euclid`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::vector::*;
+    ///
+    /// let modulus = Vector::new(3, 5);
+    ///
+    /// assert_eq!(Vector::new(4, 7).rem_euclid(modulus), (1, 2));
+    /// assert_eq!(Vector::new(-1, -7).rem_euclid(modulus), (2, 3));
+    /// assert_eq!(Vector::new(-3, -5).rem_euclid(modulus), (0, 0));
+    ///
+    /// // The sign of the modulus doesn't matter
+    /// assert_eq!(Vector::new(-1, 7).rem_euclid((-3, -5)), (2, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn rem_euclid(&self, modulus: impl VectorLike) -> Vector {
+        Vector {
+            rows: Rows(self.rows().0.rem_euclid(modulus.rows().0)),
+            columns: Columns(self.columns().0.rem_euclid(modulus.columns().0)),
+        }
+    }
+
+    /// Get the angle of this vector, in radians, in the range `[-π, π]`. The
+    /// angle is measured from the positive column direction ([`Right`]).
+    /// Because rows increase downward, positive angles are *clockwise*, as
+    /// seen on screen: [`Down`] is `π/2`, and [`Up`] is `-π/2`. The angle of
+    /// the zero vector is `0`.
+    ///
+    /// Requires the `std` or `libm` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::f64::consts::{FRAC_PI_2, PI};
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Right.angle_radians(), 0.0);
+    /// assert_eq!(Down.angle_radians(), FRAC_PI_2);
+    /// assert_eq!(Left.angle_radians(), PI);
+    /// assert_eq!(Up.angle_radians(), -FRAC_PI_2);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    #[must_use]
+    fn angle_radians(&self) -> f64 {
+        atan2(self.rows().0 as f64, self.columns().0 as f64)
+    }
+
+    /// Get the compass bearing of this vector, in degrees, in the range
+    /// `[0, 360)`. A bearing of `0` is [`Up`] (north), and bearings increase
+    /// clockwise, so [`Right`] is `90`. The bearing of the zero vector is `90`,
+    /// consistent with its [`angle_radians`][VectorLike::angle_radians].
+    ///
+    /// Requires the `std` or `libm` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly::prelude::*;
+    ///
+    /// assert_eq!(Up.bearing_degrees(), 0.0);
+    /// assert_eq!(Right.bearing_degrees(), 90.0);
+    /// assert_eq!(Down.bearing_degrees(), 180.0);
+    /// assert_eq!(Left.bearing_degrees(), 270.0);
+    /// assert_eq!(Vector::new(-1, 1).bearing_degrees(), 45.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    #[must_use]
+    fn bearing_degrees(&self) -> f64 {
+        let bearing = self.angle_radians().to_degrees() + 90.0;
+
+        if bearing < 0.0 {
+            bearing + 360.0
+        } else {
+            bearing
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+#[inline]
+fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn sqrt(value: f64) -> f64 {
+    value.sqrt()
+}
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+#[inline]
+fn sqrt(value: f64) -> f64 {
+    libm::sqrt(value)
+}
+
+/// Compute the greatest common divisor of two integers. The result is always
+/// non-negative, regardless of the signs of the inputs, and the gcd of any
+/// number and 0 is that number's absolute value. The only result that can't
+/// be represented is the magnitude of `isize::MIN` (from `gcd(isize::MIN, 0)`
+/// or `gcd(isize::MIN, isize::MIN)`), which wraps back to `isize::MIN`.
+///
+/// # Example
+///
+/// ```
+/// use gridly::vector::gcd;
+///
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(-12, 18), 6);
+/// assert_eq!(gcd(12, -18), 6);
+/// assert_eq!(gcd(7, 5), 1);
+/// assert_eq!(gcd(0, 5), 5);
+/// assert_eq!(gcd(-5, 0), 5);
+/// assert_eq!(gcd(0, 0), 0);
+/// ```
+#[must_use]
+pub const fn gcd(a: isize, b: isize) -> isize {
+    let mut a = a.unsigned_abs();
+    let mut b = b.unsigned_abs();
+
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a as isize
+}
+
+/// Compute the least common multiple of two integers. This is the smallest
+/// non-negative number that is a multiple of both inputs; it's useful for
+/// finding when several cycles of different lengths realign. If either input
+/// is 0, the result is 0.
+///
+/// # Panics
+///
+/// This panics in debug builds if the result overflows an `isize`.
+///
+/// # Example
+///
+/// ```
+/// use gridly::vector::lcm;
+///
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(-4, 6), 12);
+/// assert_eq!(lcm(3, 5), 15);
+/// assert_eq!(lcm(6, 6), 6);
+/// assert_eq!(lcm(0, 6), 0);
+/// ```
+#[must_use]
+pub const fn lcm(a: isize, b: isize) -> isize {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b)).abs() * b.abs()
+    }
 }
 
 impl VectorLike for Vector {
@@ -852,6 +1204,17 @@ impl<T: VectorLike> VectorLike for &T {
         T::checked_manhattan_length(self)
     }
 
+    #[inline]
+    fn chebyshev_length(&self) -> isize {
+        T::chebyshev_length(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn euclidean_length(&self) -> f64 {
+        T::euclidean_length(self)
+    }
+
     #[inline]
     #[must_use]
     fn clockwise(&self) -> Vector {
@@ -888,11 +1251,53 @@ impl<T: VectorLike> VectorLike for &T {
         T::transpose(self)
     }
 
+    #[inline]
+    fn reflect_across_row(&self) -> Vector {
+        T::reflect_across_row(self)
+    }
+
+    #[inline]
+    fn reflect_across_column(&self) -> Vector {
+        T::reflect_across_column(self)
+    }
+
     #[inline]
     #[must_use]
     fn direction(&self) -> Option<Direction> {
         T::direction(self)
     }
+
+    #[inline]
+    fn signum(&self) -> Vector {
+        T::signum(self)
+    }
+
+    #[inline]
+    fn abs(&self) -> Vector {
+        T::abs(self)
+    }
+
+    #[inline]
+    fn checked_abs(&self) -> Option<Vector> {
+        T::checked_abs(self)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, modulus: impl VectorLike) -> Vector {
+        T::rem_euclid(self, modulus)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn angle_radians(&self) -> f64 {
+        T::angle_radians(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn bearing_degrees(&self) -> f64 {
+        T::bearing_degrees(self)
+    }
 }
 
 impl<T: VectorLike> Add<T> for Vector {
@@ -1256,3 +1661,38 @@ pub static TOUCHING_ADJACENCIES: [Vector; 8] = [
 
 // TODO: in principle all 4 of these arrays could overlap each other. Any
 // way to do that without a slice?
+
+/// This array contains the 8 moves of a chess knight: 2 steps in one
+/// orthogonal direction, then 1 step perpendicular to it. It is intended for
+/// use with [`Grid::neighbors_by`][crate::grid::Grid::neighbors_by], or any
+/// other code that needs a non-standard adjacency. The order of the vectors
+/// is unspecified and should not be relied upon.
+///
+/// # Example
+///
+/// ```
+/// use gridly::prelude::*;
+/// use gridly::shorthand::*;
+/// let root = L(3, 3);
+/// let moves: Vec<Location> = KNIGHT_MOVES.iter().map(|v| root + v).collect();
+///
+/// assert!(moves.contains(&L(1, 2)));
+/// assert!(moves.contains(&L(1, 4)));
+/// assert!(moves.contains(&L(2, 5)));
+/// assert!(moves.contains(&L(4, 5)));
+/// assert!(moves.contains(&L(5, 4)));
+/// assert!(moves.contains(&L(5, 2)));
+/// assert!(moves.contains(&L(4, 1)));
+/// assert!(moves.contains(&L(2, 1)));
+/// assert_eq!(moves.len(), 8);
+/// ```
+pub static KNIGHT_MOVES: [Vector; 8] = [
+    Vector::new_const(-2, -1),
+    Vector::new_const(-2, 1),
+    Vector::new_const(-1, 2),
+    Vector::new_const(1, 2),
+    Vector::new_const(2, 1),
+    Vector::new_const(2, -1),
+    Vector::new_const(1, -2),
+    Vector::new_const(-1, -2),
+];
@@ -2,7 +2,19 @@
 //! Adapters for gridly grids. These adapters are designed to wrap other
 //! to provide things like translation and transposition.
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::iter::FusedIterator;
+use core::ops::{Index, IndexMut};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use gridly::grid::ZipCells;
 use gridly::prelude::*;
+use gridly::range::CrossRange;
 
 /// Grid adapter that translates the locations of the wrapped grid. The
 /// translation is added to the inner grid location; that is, if the inner
@@ -141,6 +153,47 @@ impl<G: GridBounds> ZeroRoot<G> {
     pub fn new(grid: G) -> Self {
         Self { grid }
     }
+
+    /// Get the root of the wrapped grid; that is, the location in the wrapped
+    /// grid that this adapter presents as `(0, 0)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly_adapters::ZeroRoot;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid: SparseGrid<i32> = SparseGrid::new_rooted(Row(-2) + Column(5), Rows(3) + Columns(4));
+    /// let grid = ZeroRoot::new(grid);
+    ///
+    /// assert_eq!(grid.root(), (0, 0));
+    /// assert_eq!(grid.original_root(), (-2, 5));
+    /// ```
+    pub fn original_root(&self) -> Location {
+        self.grid.root()
+    }
+
+    /// Convert a zero-based location in this adapter into the corresponding
+    /// location in the wrapped grid's coordinate system. The location doesn't
+    /// need to be in bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly_adapters::ZeroRoot;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid: SparseGrid<i32> = SparseGrid::new_rooted(Row(-2) + Column(5), Rows(3) + Columns(4));
+    /// let grid = ZeroRoot::new(grid);
+    ///
+    /// assert_eq!(grid.to_original((0, 0)), (-2, 5));
+    /// assert_eq!(grid.to_original((2, 1)), (0, 6));
+    /// ```
+    pub fn to_original(&self, zero_based: impl LocationLike) -> Location {
+        zero_based.as_location() + self.offset_to_inner_root()
+    }
 }
 
 impl<G> ZeroRoot<G> {
@@ -341,6 +394,24 @@ impl<G: GridBounds> Transpose<G> {
     }
 }
 
+impl<G> Transpose<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for Transpose<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for Transpose<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
 impl<G: GridBounds> GridBounds for Transpose<G> {
     #[inline]
     fn dimensions(&self) -> Vector {
@@ -377,3 +448,1837 @@ impl<G: GridSetter> GridSetter for Transpose<G> {
         self.grid.set_unchecked(location.transpose(), value)
     }
 }
+
+/// Grid adapter that mirrors the wrapped grid left to right. The root and
+/// dimensions are unchanged; the column of each location is reflected
+/// within the grid's column range, so the first column of this grid is the
+/// last column of the wrapped grid, and vice versa. Flipping twice is the
+/// identity.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::FlipHorizontal;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(3),
+///     1..
+/// ).unwrap();
+///
+/// let flipped = FlipHorizontal::new(grid.clone());
+///
+/// assert_eq!(flipped.dimensions(), grid.dimensions());
+/// assert_eq!(flipped.get((0, 0)), Ok(&3));
+/// assert_eq!(flipped.get((0, 2)), Ok(&1));
+/// assert_eq!(flipped.get((1, 1)), Ok(&5));
+/// assert_eq!(flipped.get((1, 0)), Ok(&6));
+/// assert!(flipped.get((0, 3)).is_err());
+///
+/// // Round-tripping two flips is the identity
+/// let round_trip = FlipHorizontal::new(flipped);
+/// assert!(round_trip.content_eq(&grid));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlipHorizontal<G> {
+    grid: G,
+}
+
+impl<G: GridBounds> FlipHorizontal<G> {
+    pub fn new(grid: G) -> Self {
+        Self { grid }
+    }
+
+    /// Map a location in this grid to the mirrored location in the wrapped
+    /// grid. This is its own inverse.
+    #[inline]
+    fn flip(&self, location: Location) -> Location {
+        let root = self.grid.root();
+        let outer = self.grid.outer_bound();
+
+        Location {
+            column: Column(root.column.0 + outer.column.0 - 1 - location.column.0),
+            ..location
+        }
+    }
+}
+
+impl<G> FlipHorizontal<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for FlipHorizontal<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for FlipHorizontal<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for FlipHorizontal<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid> Grid for FlipHorizontal<G> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        self.grid.get_unchecked(self.flip(location))
+    }
+}
+
+impl<G: GridMut> GridMut for FlipHorizontal<G> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut Self::Item {
+        let location = self.flip(location);
+        self.grid.get_unchecked_mut(location)
+    }
+}
+
+impl<G: GridSetter> GridSetter for FlipHorizontal<G> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: Self::Item) -> Self::Item {
+        let location = self.flip(location);
+        self.grid.replace_unchecked(location, value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: Self::Item) {
+        let location = self.flip(location);
+        self.grid.set_unchecked(location, value)
+    }
+}
+
+/// Grid adapter that mirrors the wrapped grid top to bottom. The root and
+/// dimensions are unchanged; the row of each location is reflected
+/// within the grid's row range, so the first row of this grid is the
+/// last row of the wrapped grid, and vice versa. Flipping twice is the
+/// identity.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::FlipVertical;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(3),
+///     1..
+/// ).unwrap();
+///
+/// let flipped = FlipVertical::new(grid.clone());
+///
+/// assert_eq!(flipped.dimensions(), grid.dimensions());
+/// assert_eq!(flipped.get((0, 0)), Ok(&4));
+/// assert_eq!(flipped.get((0, 2)), Ok(&6));
+/// assert_eq!(flipped.get((1, 0)), Ok(&1));
+/// assert_eq!(flipped.get((1, 2)), Ok(&3));
+/// assert!(flipped.get((2, 0)).is_err());
+///
+/// // Round-tripping two flips is the identity
+/// let round_trip = FlipVertical::new(flipped);
+/// assert!(round_trip.content_eq(&grid));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlipVertical<G> {
+    grid: G,
+}
+
+impl<G: GridBounds> FlipVertical<G> {
+    pub fn new(grid: G) -> Self {
+        Self { grid }
+    }
+
+    /// Map a location in this grid to the mirrored location in the wrapped
+    /// grid. This is its own inverse.
+    #[inline]
+    fn flip(&self, location: Location) -> Location {
+        let root = self.grid.root();
+        let outer = self.grid.outer_bound();
+
+        Location {
+            row: Row(root.row.0 + outer.row.0 - 1 - location.row.0),
+            ..location
+        }
+    }
+}
+
+impl<G> FlipVertical<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for FlipVertical<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for FlipVertical<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for FlipVertical<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid> Grid for FlipVertical<G> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        self.grid.get_unchecked(self.flip(location))
+    }
+}
+
+impl<G: GridMut> GridMut for FlipVertical<G> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut Self::Item {
+        let location = self.flip(location);
+        self.grid.get_unchecked_mut(location)
+    }
+}
+
+impl<G: GridSetter> GridSetter for FlipVertical<G> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: Self::Item) -> Self::Item {
+        let location = self.flip(location);
+        self.grid.replace_unchecked(location, value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: Self::Item) {
+        let location = self.flip(location);
+        self.grid.set_unchecked(location, value)
+    }
+}
+
+/// Grid adapter that rotates the wrapped grid by a [`Rotation`]. Quarter
+/// turns swap the number of rows and columns; [`Rotation::None`] and
+/// [`Rotation::Flip`] (a half turn) don't. The rotated grid has the same root
+/// as the wrapped grid, and is rotated around that root: for instance, after
+/// a [`Clockwise`] rotation, the first row of the rotated grid is the first
+/// column of the wrapped grid, read from bottom to top.
+///
+/// Unlike [`Transpose`], which only swaps the row and column of each
+/// location, rotation also reflects one of the axes.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Rotate;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(3),
+///     1..
+/// ).unwrap();
+///
+/// // 1 2 3      4 1
+/// // 4 5 6  ->  5 2
+/// //            6 3
+/// let rotated = Rotate::new(grid, Clockwise);
+///
+/// assert_eq!(rotated.dimensions(), Rows(3) + Columns(2));
+/// assert_eq!(rotated.get((0, 0)), Ok(&4));
+/// assert_eq!(rotated.get((0, 1)), Ok(&1));
+/// assert_eq!(rotated.get((2, 0)), Ok(&6));
+/// assert_eq!(rotated.get((2, 1)), Ok(&3));
+/// assert!(rotated.get((0, 2)).is_err());
+///
+/// // Rotating four times is the identity
+/// let grid = rotated.into_inner();
+/// let round_trip = Rotate::new(
+///     Rotate::new(Rotate::new(Rotate::new(&grid, Clockwise), Clockwise), Clockwise),
+///     Clockwise,
+/// );
+/// assert!(round_trip.content_eq(&grid));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rotate<G> {
+    grid: G,
+    rotation: Rotation,
+}
+
+impl<G: GridBounds> Rotate<G> {
+    pub fn new(grid: G, rotation: Rotation) -> Self {
+        Self { grid, rotation }
+    }
+
+    /// Map a location in this grid to the corresponding location in the
+    /// wrapped grid.
+    #[inline]
+    fn to_inner(&self, location: Location) -> Location {
+        let root = self.grid.root();
+        let outer = self.grid.outer_bound();
+
+        let row = location.row.0 - root.row.0;
+        let column = location.column.0 - root.column.0;
+
+        // Offsets from the last row and column of the wrapped grid
+        let last_row = |offset: isize| outer.row.0 - 1 - offset;
+        let last_column = |offset: isize| outer.column.0 - 1 - offset;
+
+        match self.rotation {
+            Rotation::None => location,
+            Rotation::Clockwise => Location::new(last_row(column), root.column.0 + row),
+            Rotation::Anticlockwise => Location::new(root.row.0 + column, last_column(row)),
+            Rotation::Flip => Location::new(last_row(row), last_column(column)),
+        }
+    }
+}
+
+impl<G> Rotate<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    /// Get the rotation applied by this adapter
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+}
+
+impl<G> AsRef<G> for Rotate<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for Rotate<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for Rotate<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        let dimensions = self.grid.dimensions();
+
+        if self.rotation.is_turn() {
+            dimensions.transpose()
+        } else {
+            dimensions
+        }
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid> Grid for Rotate<G> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        self.grid.get_unchecked(self.to_inner(location))
+    }
+}
+
+impl<G: GridMut> GridMut for Rotate<G> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut Self::Item {
+        let location = self.to_inner(location);
+        self.grid.get_unchecked_mut(location)
+    }
+}
+
+impl<G: GridSetter> GridSetter for Rotate<G> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: Self::Item) -> Self::Item {
+        let location = self.to_inner(location);
+        self.grid.replace_unchecked(location, value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: Self::Item) {
+        let location = self.to_inner(location);
+        self.grid.set_unchecked(location, value)
+    }
+}
+
+/// Grid adapter that calls a function with the location of every read from
+/// the wrapped grid. This is the grid equivalent of [`Iterator::inspect`],
+/// and is mostly useful for debugging, to see which cells an algorithm
+/// touches. Only reads through [`get_unchecked`][Grid::get_unchecked] (which
+/// includes [`get`][Grid::get] and all views) are reported; mutable access
+/// and writes are forwarded silently.
+///
+/// # Example
+///
+/// ```
+/// use core::cell::Cell;
+///
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Inspect;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(3),
+///     1..
+/// ).unwrap();
+///
+/// let reads = Cell::new(0);
+/// let last = Cell::new(None);
+///
+/// let grid = Inspect::new(grid, |location| {
+///     reads.set(reads.get() + 1);
+///     last.set(Some(location));
+/// });
+///
+/// assert_eq!(grid.get((1, 2)).ok(), Some(&6));
+/// assert_eq!(reads.get(), 1);
+/// assert_eq!(last.get(), Some(Location::new(1, 2)));
+///
+/// // Out of bounds accesses never reach the inner grid
+/// assert_eq!(grid.get((2, 0)).ok(), None);
+/// assert_eq!(reads.get(), 1);
+///
+/// let sum: i32 = grid.row(0).unwrap().iter().sum();
+/// assert_eq!(sum, 6);
+/// assert_eq!(reads.get(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Inspect<G, F> {
+    grid: G,
+    on_read: F,
+}
+
+impl<G: Grid, F: Fn(Location)> Inspect<G, F> {
+    pub fn new(grid: G, on_read: F) -> Self {
+        Self { grid, on_read }
+    }
+}
+
+impl<G, F> Inspect<G, F> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G, F> AsRef<G> for Inspect<G, F> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G, F> AsMut<G> for Inspect<G, F> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds, F> GridBounds for Inspect<G, F> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid, F: Fn(Location)> Grid for Inspect<G, F> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        (self.on_read)(location);
+        self.grid.get_unchecked(location)
+    }
+}
+
+impl<G: GridMut, F: Fn(Location)> GridMut for Inspect<G, F> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut Self::Item {
+        self.grid.get_unchecked_mut(location)
+    }
+}
+
+impl<G: GridSetter, F: Fn(Location)> GridSetter for Inspect<G, F> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: Self::Item) -> Self::Item {
+        self.grid.replace_unchecked(location, value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: Self::Item) {
+        self.grid.set_unchecked(location, value)
+    }
+}
+
+/// Grid adapter that hides some of the cells of the wrapped grid. Cells for
+/// which the `mask` function returns `false` read as a shared `default`
+/// value, while the rest are forwarded to the wrapped grid. The dimensions
+/// and root are unchanged. This is useful for restricting an algorithm to an
+/// arbitrarily shaped region of a grid.
+///
+/// Because masked-out cells don't correspond to anything in the wrapped grid,
+/// this adapter is read-only; use [`as_mut`][AsMut::as_mut] to modify the
+/// wrapped grid.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Masked;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(3) + Columns(3),
+///     1..
+/// ).unwrap();
+///
+/// let grid = Masked::new(grid, 0, |location: Location| location.row.0 == location.column.0);
+///
+/// assert_eq!(grid.dimensions(), (3, 3));
+///
+/// let cells: Vec<i32> = grid.rows().iter().flat_map(|row| row.iter().copied()).collect();
+/// assert_eq!(cells, [1, 0, 0, 0, 5, 0, 0, 0, 9]);
+///
+/// // Out of bounds is still out of bounds
+/// assert_eq!(grid.get((3, 3)).ok(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Masked<G: Grid, F> {
+    grid: G,
+    default: G::Item,
+    mask: F,
+}
+
+impl<G: Grid, F: Fn(Location) -> bool> Masked<G, F> {
+    pub fn new(grid: G, default: G::Item, mask: F) -> Self {
+        Self {
+            grid,
+            default,
+            mask,
+        }
+    }
+}
+
+impl<G: Grid, F> Masked<G, F> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    /// Get a reference to the value that masked-out cells read as
+    pub fn get_default(&self) -> &G::Item {
+        &self.default
+    }
+}
+
+impl<G: Grid, F> AsRef<G> for Masked<G, F> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G: Grid, F> AsMut<G> for Masked<G, F> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: Grid, F> GridBounds for Masked<G, F> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid, F: Fn(Location) -> bool> Grid for Masked<G, F> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        if (self.mask)(location) {
+            self.grid.get_unchecked(location)
+        } else {
+            &self.default
+        }
+    }
+}
+
+/// Grid adapter that allows any grid to be indexed with `grid[location]`
+/// syntax. Concrete grids like `VecGrid` implement [`Index`] directly, but
+/// generic grids, adapters, and trait objects don't; this adapter provides
+/// uniform indexing for them. Like the concrete grids, indexing panics if the
+/// location is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::{Indexed, Translate};
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(2),
+///     [1, 2, 3, 4].iter().copied()
+/// ).unwrap();
+///
+/// let mut grid = Indexed::new(Translate::new(grid, Rows(5) + Columns(5)));
+///
+/// assert_eq!(grid[(5, 5)], 1);
+/// assert_eq!(grid[Location::new(6, 5)], 3);
+///
+/// grid[(6, 6)] = 10;
+/// assert_eq!(grid[(6, 6)], 10);
+/// ```
+///
+/// Indexing out of bounds panics:
+///
+/// ```should_panic
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Indexed;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new(Rows(2) + Columns(2)).unwrap();
+/// let grid = Indexed::new(grid);
+///
+/// let _ = grid[(2, 0)];
+/// ```
+#[derive(Debug, Clone)]
+pub struct Indexed<G> {
+    grid: G,
+}
+
+impl<G: Grid> Indexed<G> {
+    pub fn new(grid: G) -> Self {
+        Self { grid }
+    }
+}
+
+impl<G> Indexed<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for Indexed<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for Indexed<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for Indexed<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid> Grid for Indexed<G> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        self.grid.get_unchecked(location)
+    }
+}
+
+impl<G: GridMut> GridMut for Indexed<G> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut Self::Item {
+        self.grid.get_unchecked_mut(location)
+    }
+}
+
+impl<G: GridSetter> GridSetter for Indexed<G> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: Self::Item) -> Self::Item {
+        self.grid.replace_unchecked(location, value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: Self::Item) {
+        self.grid.set_unchecked(location, value)
+    }
+}
+
+impl<G: Grid, L: LocationLike> Index<L> for Indexed<G> {
+    type Output = G::Item;
+
+    fn index(&self, location: L) -> &G::Item {
+        self.get(&location).unwrap_or_else(|bounds_err| {
+            panic!("{:?} out of bounds: {}", location.as_location(), bounds_err)
+        })
+    }
+}
+
+impl<G: GridMut, L: LocationLike> IndexMut<L> for Indexed<G> {
+    fn index_mut(&mut self, location: L) -> &mut G::Item {
+        self.get_mut(&location).unwrap_or_else(|bounds_err| {
+            panic!("{:?} out of bounds: {}", location.as_location(), bounds_err)
+        })
+    }
+}
+
+/// Grid adapter that overrides the border cells of the wrapped grid. Cells in
+/// the first or last row or column of the grid read as a shared `value`,
+/// while interior cells are forwarded to the wrapped grid. The dimensions and
+/// root are unchanged. This is useful for simulations with fixed boundary
+/// conditions, without needing to copy the grid.
+///
+/// Because the border cells don't correspond to anything in the wrapped grid,
+/// this adapter is read-only; use [`as_mut`][AsMut::as_mut] to modify the
+/// wrapped grid.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::BorderValue;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(3) + Columns(4),
+///     1..
+/// ).unwrap();
+///
+/// let grid = BorderValue::new(grid, 0);
+///
+/// // Corners and edges read the border value
+/// assert_eq!(grid.get((0, 0)), Ok(&0));
+/// assert_eq!(grid.get((2, 3)), Ok(&0));
+/// assert_eq!(grid.get((1, 0)), Ok(&0));
+///
+/// // Interior cells read through
+/// assert_eq!(grid.get((1, 1)), Ok(&6));
+/// assert_eq!(grid.get((1, 2)), Ok(&7));
+///
+/// // Out of bounds is still out of bounds
+/// assert!(grid.get((3, 0)).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BorderValue<G: Grid> {
+    grid: G,
+    value: G::Item,
+}
+
+impl<G: Grid> BorderValue<G> {
+    pub fn new(grid: G, value: G::Item) -> Self {
+        Self { grid, value }
+    }
+
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    /// Get a reference to the value that border cells read as
+    pub fn get_value(&self) -> &G::Item {
+        &self.value
+    }
+}
+
+impl<G: Grid> AsRef<G> for BorderValue<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G: Grid> AsMut<G> for BorderValue<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: Grid> GridBounds for BorderValue<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid> Grid for BorderValue<G> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        let root = self.grid.root();
+        let last = self.grid.outer_bound() - (Rows(1) + Columns(1));
+
+        if location.row == root.row
+            || location.row == last.row
+            || location.column == root.column
+            || location.column == last.column
+        {
+            &self.value
+        } else {
+            self.grid.get_unchecked(location)
+        }
+    }
+}
+
+/// Grid adapter that clamps out of bounds reads to the nearest edge cell of
+/// the wrapped grid. A read at any location returns the cell at
+/// [`clamp_location`][GridBounds::clamp_location], so reads past an edge
+/// return the cell on that edge, and reads past a corner return the corner.
+/// This is the "clamp to edge" addressing mode used in texture sampling.
+///
+/// Because reads never fail for out of bounds locations, this adapter
+/// doesn't implement [`Grid`], whose `get` always bounds checks. Instead, it
+/// provides an inherent [`get`][ClampEdge::get] method. It is read-only; use
+/// [`as_mut`][AsMut::as_mut] to modify the wrapped grid.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::ClampEdge;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(3) + Columns(4),
+///     1..
+/// ).unwrap();
+///
+/// let grid = ClampEdge::new(grid);
+///
+/// // In bounds reads are forwarded unchanged
+/// assert_eq!(grid.get((1, 1)), Ok(&6));
+///
+/// // Reads past an edge return the nearest cell on that edge
+/// assert_eq!(grid.get((-10, 2)), Ok(&3));
+/// assert_eq!(grid.get((1, 100)), Ok(&8));
+///
+/// // Reads past a corner return the corner
+/// assert_eq!(grid.get((-5, -5)), Ok(&1));
+/// assert_eq!(grid.get((50, 50)), Ok(&12));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClampEdge<G> {
+    grid: G,
+}
+
+impl<G: Grid> ClampEdge<G> {
+    pub fn new(grid: G) -> Self {
+        Self { grid }
+    }
+
+    /// Get the cell at `location`, or the nearest in-bounds cell if it's out
+    /// of bounds. This only returns an error if the grid is empty, and so
+    /// has no cells at all.
+    pub fn get(&self, location: impl LocationLike) -> Result<&G::Item, BoundsError> {
+        self.grid.get(self.grid.clamp_location(location))
+    }
+}
+
+impl<G> ClampEdge<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for ClampEdge<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for ClampEdge<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for ClampEdge<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+/// Grid adapter that lazily transforms the cells of the wrapped grid with a
+/// function. Each read calls `func` on the wrapped cell and returns the
+/// result; nothing is computed or stored ahead of time.
+///
+/// [`Grid::get_unchecked`] must return a reference to a cell stored in the
+/// grid, and the transformed values aren't stored anywhere, so this adapter
+/// can't implement [`Grid`]. Instead, like [`WithLocations`], it provides an
+/// inherent [`get`][Map::get] method and a [`cells`][Map::cells] iterator,
+/// both of which return the transformed values by value. It is read-only;
+/// use [`as_mut`][AsMut::as_mut] to modify the wrapped grid. To store the
+/// transformed grid, collect [`cells`][Map::cells] into a new grid.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Map;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<String> = VecGrid::new_row_major(
+///     Rows(2) + Columns(2),
+///     vec!["a".to_string(), "bc".to_string(), "Def".to_string(), "".to_string()],
+/// ).unwrap();
+///
+/// let grid = Map::new(grid, |cell: &String| cell.to_uppercase());
+///
+/// assert_eq!(grid.get((0, 1)), Ok("BC".to_string()));
+/// assert_eq!(grid.get((1, 0)), Ok("DEF".to_string()));
+/// assert!(grid.get((2, 0)).is_err());
+///
+/// let cells: Vec<String> = grid.cells().map(|(_, cell)| cell).collect();
+/// assert_eq!(cells, ["A", "BC", "DEF", ""]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Map<G, F> {
+    grid: G,
+    func: F,
+}
+
+impl<G: Grid, U, F: Fn(&G::Item) -> U> Map<G, F> {
+    pub fn new(grid: G, func: F) -> Self {
+        Self { grid, func }
+    }
+
+    /// Get the transformed value of the cell at `location`. Returns an error
+    /// if the location is out of bounds.
+    pub fn get(&self, location: impl LocationLike) -> Result<U, BoundsError> {
+        self.grid.get(location).map(&self.func)
+    }
+
+    /// Get an iterator over the transformed values of all the cells in the
+    /// grid, paired with their locations, in row-major order.
+    pub fn cells(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Location, U)> + ExactSizeIterator + FusedIterator + Clone + '_
+    {
+        let grid = &self.grid;
+        let func = &self.func;
+
+        CrossRange::new(grid.row_range(), grid.column_range())
+            // Safety: the range is always within the grid's bounds
+            .map(move |location| (location, func(unsafe { grid.get_unchecked(location) })))
+    }
+}
+
+impl<G, F> Map<G, F> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G, F> AsRef<G> for Map<G, F> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G, F> AsMut<G> for Map<G, F> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds, F> GridBounds for Map<G, F> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+/// Grid adapter that transforms values on their way into the wrapped grid.
+/// This is the write-side counterpart to [`Map`]: values written with
+/// [`set`][MapWrite::set] or [`replace`][MapWrite::replace] are passed
+/// through `func` before being stored, which allows the grid to accept
+/// values of one type and store another, such as parsing characters into an
+/// enum.
+///
+/// Note the asymmetry: writes take the input type of `func`, but reads, via
+/// [`Grid`], return the wrapped grid's own item type, as stored. Because the
+/// write type differs from [`Grid::Item`], this adapter provides inherent
+/// `set` and `replace` methods rather than implementing [`GridSetter`].
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::MapWrite;
+/// use gridly::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Tile {
+///     Floor,
+///     Wall,
+/// }
+///
+/// let grid = VecGrid::new_fill(Rows(2) + Columns(2), &Tile::Floor).unwrap();
+///
+/// let mut grid = MapWrite::new(grid, |c: char| match c {
+///     '#' => Tile::Wall,
+///     _ => Tile::Floor,
+/// });
+///
+/// grid.set((0, 1), '#').unwrap();
+/// assert_eq!(grid.get((0, 1)), Ok(&Tile::Wall));
+/// assert_eq!(grid.get((0, 0)), Ok(&Tile::Floor));
+///
+/// assert_eq!(grid.replace((0, 1), '.'), Ok(Tile::Wall));
+/// assert_eq!(grid.get((0, 1)), Ok(&Tile::Floor));
+///
+/// assert!(grid.set((2, 0), '#').is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MapWrite<G, F> {
+    grid: G,
+    func: F,
+}
+
+impl<G: GridSetter, F> MapWrite<G, F> {
+    pub fn new(grid: G, func: F) -> Self {
+        Self { grid, func }
+    }
+
+    /// Transform `value` and store it at `location`. Returns an error if the
+    /// location is out of bounds, in which case `func` isn't called.
+    pub fn set<V>(&mut self, location: impl LocationLike, value: V) -> Result<(), BoundsError>
+    where
+        F: Fn(V) -> G::Item,
+    {
+        let location = self.grid.check_location(location)?;
+        let value = (self.func)(value);
+
+        // Safety: the location was just bounds checked
+        unsafe { self.grid.set_unchecked(location, value) };
+        Ok(())
+    }
+
+    /// Transform `value` and store it at `location`, returning the previous
+    /// stored value. Returns an error if the location is out of bounds, in
+    /// which case `func` isn't called.
+    pub fn replace<V>(
+        &mut self,
+        location: impl LocationLike,
+        value: V,
+    ) -> Result<G::Item, BoundsError>
+    where
+        F: Fn(V) -> G::Item,
+    {
+        let location = self.grid.check_location(location)?;
+        let value = (self.func)(value);
+
+        // Safety: the location was just bounds checked
+        Ok(unsafe { self.grid.replace_unchecked(location, value) })
+    }
+}
+
+impl<G, F> MapWrite<G, F> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G, F> AsRef<G> for MapWrite<G, F> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G, F> AsMut<G> for MapWrite<G, F> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds, F> GridBounds for MapWrite<G, F> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+impl<G: Grid, F> Grid for MapWrite<G, F> {
+    type Item = G::Item;
+
+    #[inline]
+    unsafe fn get_unchecked(&self, location: Location) -> &Self::Item {
+        self.grid.get_unchecked(location)
+    }
+}
+
+/// Grid adapter that reads two grids in lockstep. The bounds of the zipped
+/// grid are the intersection of the bounds of the two wrapped grids (see
+/// [`intersect_bounds`][GridBounds::intersect_bounds]), and each location
+/// reads the pair of cells at that same location in both grids.
+///
+/// The grids are matched up by location, not by offset from their roots, so
+/// grids with different roots are zipped over the region where they
+/// overlap. If they don't overlap at all, the zipped grid is empty.
+///
+/// [`Grid::get_unchecked`] must return a reference to a cell stored in the
+/// grid, and the pairs produced by this adapter aren't stored anywhere, so
+/// this adapter can't implement [`Grid`]. Instead, like [`WithLocations`],
+/// it provides an inherent [`get`][Zip::get] method and a
+/// [`zip_locations`][Zip::zip_locations] iterator, both of which return the
+/// pairs by value.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::{SparseGrid, VecGrid};
+/// use gridly_adapters::Zip;
+/// use gridly::prelude::*;
+///
+/// let terrain = VecGrid::new_row_major(Rows(2) + Columns(3), "..#.#.".chars()).unwrap();
+/// let mut overlay = SparseGrid::new_rooted_default((0, 1), Rows(3) + Columns(3), ' ');
+/// overlay.set((1, 2), '@').unwrap();
+///
+/// let zipped = Zip::new(&terrain, &overlay);
+///
+/// assert_eq!(zipped.root(), (0, 1));
+/// assert_eq!(zipped.dimensions(), (2, 2));
+///
+/// assert_eq!(zipped.get((1, 2)), Ok((&'.', &'@')));
+/// assert_eq!(zipped.get((0, 2)), Ok((&'#', &' ')));
+/// assert!(zipped.get((0, 0)).is_err());
+///
+/// let overlaid: String = zipped
+///     .zip_locations()
+///     .map(|(_, &tile, &over)| if over == ' ' { tile } else { over })
+///     .collect();
+///
+/// assert_eq!(overlaid, ".##@");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Zip<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Grid, B: Grid> Zip<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Get the pair of cells at `location`. Returns an error if the location
+    /// is out of bounds of either grid.
+    pub fn get(&self, location: impl LocationLike) -> Result<(&A::Item, &B::Item), BoundsError> {
+        let location = self.check_location(location)?;
+
+        // Safety: the location was just checked against the intersection of
+        // the bounds of both grids
+        Ok(unsafe {
+            (
+                self.first.get_unchecked(location),
+                self.second.get_unchecked(location),
+            )
+        })
+    }
+
+    /// Get an iterator over the pairs of cells in the zipped grid, along with
+    /// their locations, in row-major order.
+    pub fn zip_locations(&self) -> ZipCells<'_, A, B> {
+        self.first.zip_cells(&self.second)
+    }
+}
+
+impl<A, B> Zip<A, B> {
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    /// Get a reference to the first of the zipped grids
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Get a reference to the second of the zipped grids
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A: GridBounds, B: GridBounds> GridBounds for Zip<A, B> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.first.intersect_bounds(&self.second).1
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.first.intersect_bounds(&self.second).0
+    }
+}
+
+/// Grid adapter that pairs each cell of the wrapped grid with its location.
+/// This is useful for algorithms that move cells around and need to
+/// remember where each one came from.
+///
+/// [`Grid::get_unchecked`] must return a reference to a cell stored in the
+/// grid, and the `(Location, &Item)` pairs produced by this adapter aren't
+/// stored anywhere, so this adapter can't implement [`Grid`]. Instead, it
+/// provides an inherent [`get`][WithLocations::get] method and a
+/// [`cells`][WithLocations::cells] iterator, both of which return the pairs
+/// by value.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::{Translate, WithLocations};
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<char> = VecGrid::new_row_major(
+///     Rows(2) + Columns(2),
+///     "abcd".chars()
+/// ).unwrap();
+///
+/// let grid = WithLocations::new(Translate::new(grid, Rows(3) + Columns(3)));
+///
+/// assert_eq!(grid.get((4, 3)), Ok((Location::new(4, 3), &'c')));
+/// assert!(grid.get((0, 0)).is_err());
+///
+/// let cells: Vec<(Location, char)> = grid.cells().map(|(loc, &cell)| (loc, cell)).collect();
+/// assert_eq!(cells, [
+///     (Location::new(3, 3), 'a'),
+///     (Location::new(3, 4), 'b'),
+///     (Location::new(4, 3), 'c'),
+///     (Location::new(4, 4), 'd'),
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithLocations<G> {
+    grid: G,
+}
+
+impl<G: Grid> WithLocations<G> {
+    pub fn new(grid: G) -> Self {
+        Self { grid }
+    }
+
+    /// Get the cell at `location`, paired with that location. Returns an
+    /// error if the location is out of bounds.
+    pub fn get(&self, location: impl LocationLike) -> Result<(Location, &G::Item), BoundsError> {
+        let location = self.grid.check_location(location)?;
+
+        // Safety: the location was just bounds checked
+        Ok((location, unsafe { self.grid.get_unchecked(location) }))
+    }
+
+    /// Get an iterator over all the cells in the grid, paired with their
+    /// locations, in row-major order.
+    pub fn cells(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Location, &G::Item)> + ExactSizeIterator + FusedIterator + Clone
+    {
+        let grid = &self.grid;
+
+        CrossRange::new(grid.row_range(), grid.column_range())
+            // Safety: the range is always within the grid's bounds
+            .map(move |location| (location, unsafe { grid.get_unchecked(location) }))
+    }
+}
+
+impl<G> WithLocations<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> AsRef<G> for WithLocations<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> AsMut<G> for WithLocations<G> {
+    fn as_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G: GridBounds> GridBounds for WithLocations<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+/// Grid adapter that memoizes reads from the wrapped grid. This is useful
+/// when reading cells from the wrapped grid is expensive, such as with
+/// adapters that compute their cells on demand. The first read of each
+/// location is forwarded to the wrapped grid, and the result is cloned into
+/// an internal cache; subsequent reads of that location return a clone of the
+/// cached value.
+///
+/// Because the cache is filled through a shared reference, and values are
+/// returned by clone, this adapter doesn't implement [`Grid`]; use
+/// [`get_cached`][Cached::get_cached] to read cells. It is also read-only,
+/// since writes to the wrapped grid would invalidate the cache.
+///
+/// This adapter requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly_adapters::Cached;
+/// use gridly::prelude::*;
+///
+/// let grid: VecGrid<i32> = VecGrid::new_row_major(
+///     Rows(2) + Columns(2),
+///     [1, 2, 3, 4].iter().copied()
+/// ).unwrap();
+///
+/// let grid = Cached::new(grid);
+///
+/// assert_eq!(grid.get_cached((1, 0)), Ok(3));
+/// assert_eq!(grid.get_cached((1, 0)), Ok(3));
+/// assert_eq!(grid.cached_count(), 1);
+///
+/// assert!(grid.get_cached((2, 0)).is_err());
+/// assert_eq!(grid.cached_count(), 1);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Cached<G: Grid> {
+    grid: G,
+    cache: RefCell<HashMap<Location, G::Item>>,
+}
+
+#[cfg(feature = "std")]
+impl<G: Grid> Cached<G>
+where
+    G::Item: Clone,
+{
+    pub fn new(grid: G) -> Self {
+        Self {
+            grid,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Get a clone of the cell at `location`. The wrapped grid is only read
+    /// the first time each location is requested; after that, the cached
+    /// value is used. Returns an error if the location is out of bounds.
+    pub fn get_cached(&self, location: impl LocationLike) -> Result<G::Item, BoundsError> {
+        let location = self.grid.check_location(location)?;
+
+        // Safety: the location was just bounds checked
+        Ok(self
+            .cache
+            .borrow_mut()
+            .entry(location)
+            .or_insert_with(|| unsafe { self.grid.get_unchecked(location) }.clone())
+            .clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: Grid> Cached<G> {
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    /// Get the number of locations currently in the cache
+    pub fn cached_count(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Discard all cached values, so that subsequent reads are forwarded to
+    /// the wrapped grid again.
+    pub fn clear_cache(&mut self) {
+        self.cache.get_mut().clear()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: Grid> AsRef<G> for Cached<G> {
+    fn as_ref(&self) -> &G {
+        &self.grid
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: Grid> GridBounds for Cached<G> {
+    #[inline]
+    fn dimensions(&self) -> Vector {
+        self.grid.dimensions()
+    }
+
+    #[inline]
+    fn root(&self) -> Location {
+        self.grid.root()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gridly::prelude::*;
+    use gridly::shorthand::L;
+    use gridly_grids::VecGrid;
+
+    use crate::*;
+
+    /// A 3x4 grid, rooted at (0, 0), containing 1 through 12
+    fn make_grid() -> VecGrid<i32> {
+        VecGrid::new_row_major(Rows(3) + Columns(4), 1..).unwrap()
+    }
+
+    /// Write to `outer` through the adapter with each of the safe mutation
+    /// methods, and check that the write is visible at `outer` through the
+    /// adapter and at `inner` in the wrapped grid, which is retrieved with
+    /// `unwrap`.
+    fn check_round_trip<A>(
+        mut adapter: A,
+        unwrap: fn(&A) -> &VecGrid<i32>,
+        outer: Location,
+        inner: Location,
+    ) where
+        A: GridMut<Item = i32> + GridSetter,
+    {
+        let original = unwrap(&adapter)[inner];
+        assert_eq!(adapter.get(outer), Ok(&original));
+
+        adapter.set(outer, 100).unwrap();
+        assert_eq!(adapter.get(outer), Ok(&100));
+        assert_eq!(unwrap(&adapter)[inner], 100);
+
+        assert_eq!(adapter.replace(outer, 200), Ok(100));
+        assert_eq!(adapter.get(outer), Ok(&200));
+        assert_eq!(unwrap(&adapter)[inner], 200);
+
+        *adapter.get_mut(outer).unwrap() += 1;
+        assert_eq!(adapter.get(outer), Ok(&201));
+        assert_eq!(unwrap(&adapter)[inner], 201);
+
+        // Every other cell is untouched
+        for row in unwrap(&adapter).rows().iter() {
+            for (location, &cell) in row.iter_with_locations() {
+                if location != inner {
+                    assert_eq!(make_grid()[location], cell);
+                }
+            }
+        }
+    }
+
+    /// Check that out of bounds writes through the adapter fail, and don't
+    /// modify the wrapped grid.
+    fn check_out_of_bounds<A>(mut adapter: A, unwrap: fn(&A) -> &VecGrid<i32>, outer: Location)
+    where
+        A: GridMut<Item = i32> + GridSetter,
+    {
+        assert!(adapter.set(outer, 100).is_err());
+        assert!(adapter.replace(outer, 100).is_err());
+        assert!(adapter.get_mut(outer).is_err());
+        assert!(unwrap(&adapter).content_eq(&make_grid()));
+    }
+
+    #[test]
+    fn test_translate_round_trip() {
+        let make = || Translate::new(make_grid(), Rows(-2) + Columns(5));
+        let unwrap = Translate::as_ref;
+
+        check_round_trip(make(), unwrap, L(-2, 5), L(0, 0));
+        check_round_trip(make(), unwrap, L(0, 8), L(2, 3));
+        check_round_trip(make(), unwrap, L(-1, 6), L(1, 1));
+
+        check_out_of_bounds(make(), unwrap, L(0, 0));
+        check_out_of_bounds(make(), unwrap, L(1, 5));
+    }
+
+    #[test]
+    fn test_zero_root_round_trip() {
+        let make = || ZeroRoot::new(Translate::new(make_grid(), Rows(3) + Columns(-3)));
+        fn unwrap(grid: &ZeroRoot<Translate<VecGrid<i32>>>) -> &VecGrid<i32> {
+            grid.as_ref().as_ref()
+        }
+
+        check_round_trip(make(), unwrap, L(0, 0), L(0, 0));
+        check_round_trip(make(), unwrap, L(2, 3), L(2, 3));
+        check_round_trip(make(), unwrap, L(1, 2), L(1, 2));
+
+        check_out_of_bounds(make(), unwrap, L(3, -3));
+        check_out_of_bounds(make(), unwrap, L(-1, 0));
+    }
+
+    #[test]
+    fn test_window_round_trip() {
+        let make = || Window::new(make_grid(), Row(1) + Column(1), Rows(2) + Columns(2));
+        let unwrap = Window::as_ref;
+
+        check_round_trip(make(), unwrap, L(1, 1), L(1, 1));
+        check_round_trip(make(), unwrap, L(2, 2), L(2, 2));
+
+        check_out_of_bounds(make(), unwrap, L(0, 0));
+        check_out_of_bounds(make(), unwrap, L(1, 3));
+        check_out_of_bounds(make(), unwrap, L(2, 0));
+    }
+
+    #[test]
+    fn test_transpose_round_trip() {
+        let make = || Transpose::new(make_grid());
+        let unwrap = Transpose::as_ref;
+
+        check_round_trip(make(), unwrap, L(0, 0), L(0, 0));
+        check_round_trip(make(), unwrap, L(3, 0), L(0, 3));
+        check_round_trip(make(), unwrap, L(1, 2), L(2, 1));
+
+        check_out_of_bounds(make(), unwrap, L(0, 3));
+        check_out_of_bounds(make(), unwrap, L(4, 0));
+    }
+
+    #[test]
+    fn test_flip_horizontal_round_trip() {
+        let make = || FlipHorizontal::new(Translate::new(make_grid(), Rows(-1) + Columns(2)));
+        let unwrap: fn(&FlipHorizontal<Translate<VecGrid<i32>>>) -> &VecGrid<i32> =
+            |grid| grid.as_ref().as_ref();
+
+        check_round_trip(make(), unwrap, L(-1, 2), L(0, 3));
+        check_round_trip(make(), unwrap, L(0, 4), L(1, 1));
+        check_round_trip(make(), unwrap, L(1, 5), L(2, 0));
+
+        check_out_of_bounds(make(), unwrap, L(-1, 6));
+        check_out_of_bounds(make(), unwrap, L(2, 2));
+    }
+
+    #[test]
+    fn test_flip_vertical_round_trip() {
+        let make = || FlipVertical::new(Translate::new(make_grid(), Rows(-1) + Columns(2)));
+        let unwrap: fn(&FlipVertical<Translate<VecGrid<i32>>>) -> &VecGrid<i32> =
+            |grid| grid.as_ref().as_ref();
+
+        check_round_trip(make(), unwrap, L(-1, 2), L(2, 0));
+        check_round_trip(make(), unwrap, L(0, 4), L(1, 2));
+        check_round_trip(make(), unwrap, L(1, 5), L(0, 3));
+
+        check_out_of_bounds(make(), unwrap, L(-2, 2));
+        check_out_of_bounds(make(), unwrap, L(0, 6));
+    }
+
+    #[test]
+    fn test_rotate_round_trip() {
+        let unwrap = Rotate::as_ref;
+
+        // Translate the grid so that the rotations are around a non-zero root
+        let grid = || Translate::new(make_grid(), Rows(-1) + Columns(2));
+        let unwrap_translated: fn(&Rotate<Translate<VecGrid<i32>>>) -> &VecGrid<i32> =
+            |grid| grid.as_ref().as_ref();
+
+        let make = || Rotate::new(make_grid(), Rotation::None);
+        check_round_trip(make(), unwrap, L(1, 2), L(1, 2));
+        check_out_of_bounds(make(), unwrap, L(0, 4));
+
+        let make = || Rotate::new(grid(), Clockwise);
+        check_round_trip(make(), unwrap_translated, L(-1, 2), L(2, 0));
+        check_round_trip(make(), unwrap_translated, L(-1, 4), L(0, 0));
+        check_round_trip(make(), unwrap_translated, L(2, 3), L(1, 3));
+        check_out_of_bounds(make(), unwrap_translated, L(-1, 5));
+        check_out_of_bounds(make(), unwrap_translated, L(3, 2));
+
+        let make = || Rotate::new(grid(), Anticlockwise);
+        check_round_trip(make(), unwrap_translated, L(-1, 2), L(0, 3));
+        check_round_trip(make(), unwrap_translated, L(2, 2), L(0, 0));
+        check_round_trip(make(), unwrap_translated, L(1, 3), L(1, 1));
+        check_out_of_bounds(make(), unwrap_translated, L(-1, 5));
+
+        let make = || Rotate::new(grid(), Rotation::Flip);
+        check_round_trip(make(), unwrap_translated, L(-1, 2), L(2, 3));
+        check_round_trip(make(), unwrap_translated, L(0, 3), L(1, 2));
+        check_out_of_bounds(make(), unwrap_translated, L(2, 2));
+    }
+
+    #[test]
+    fn test_rotate_four_times() {
+        let grid = Translate::new(make_grid(), Rows(3) + Columns(-5));
+
+        for &rotation in &[Clockwise, Anticlockwise, Rotation::Flip] {
+            let rotated = Rotate::new(&grid, rotation);
+
+            assert!(!rotated.content_eq(&grid));
+            assert_eq!(rotated.root(), grid.root());
+
+            let rotated = Rotate::new(
+                Rotate::new(Rotate::new(rotated, rotation), rotation),
+                rotation,
+            );
+            assert!(rotated.content_eq(&grid));
+        }
+    }
+
+    #[test]
+    fn test_rotate_window() {
+        let grid = Window::new(
+            Rotate::new(make_grid(), Clockwise),
+            L(1, 1),
+            Rows(2) + Columns(2),
+        );
+
+        assert_eq!(grid.get(L(1, 1)), Ok(&6));
+        assert_eq!(grid.get(L(1, 2)), Ok(&2));
+        assert_eq!(grid.get(L(2, 1)), Ok(&7));
+        assert_eq!(grid.get(L(2, 2)), Ok(&3));
+        assert!(grid.get(L(0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_indexed_round_trip() {
+        let make = || Indexed::new(make_grid());
+        let unwrap = Indexed::as_ref;
+
+        check_round_trip(make(), unwrap, L(2, 1), L(2, 1));
+        check_out_of_bounds(make(), unwrap, L(3, 0));
+
+        let mut grid = make();
+        grid[(1, 1)] = 50;
+        assert_eq!(grid.as_ref()[(1, 1)], 50);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cached_reads_once() {
+        use core::cell::Cell;
+
+        let reads: [[Cell<usize>; 4]; 3] = Default::default();
+
+        let grid = Cached::new(Inspect::new(make_grid(), |location: Location| {
+            let cell = &reads[location.row.0 as usize][location.column.0 as usize];
+            cell.set(cell.get() + 1);
+        }));
+
+        for _ in 0..3 {
+            for row in 0..3 {
+                for column in 0..4 {
+                    let location = L(row, column);
+                    assert_eq!(grid.get_cached(location), Ok(make_grid()[location]));
+                }
+            }
+        }
+
+        assert!(reads.iter().flatten().all(|count| count.get() == 1));
+        assert_eq!(grid.cached_count(), 12);
+
+        assert!(grid.get_cached(L(3, 0)).is_err());
+        assert_eq!(grid.cached_count(), 12);
+    }
+
+    #[test]
+    fn test_zero_root_to_original() {
+        let grid = ZeroRoot::new(Translate::new(make_grid(), Rows(-3) + Columns(7)));
+
+        assert_eq!(grid.original_root(), L(-3, 7));
+
+        for row in grid.rows().iter() {
+            for (location, cell) in row.iter_with_locations() {
+                let original = grid.to_original(location);
+
+                assert_eq!(grid.as_ref().get(original), Ok(cell));
+                assert_eq!(original - grid.original_root(), location - grid.root());
+            }
+        }
+
+        assert_eq!(grid.to_original(L(-1, -1)), L(-4, 6));
+    }
+
+    #[test]
+    fn test_with_locations() {
+        let grid = WithLocations::new(Translate::new(make_grid(), Rows(-1) + Columns(2)));
+
+        assert_eq!(grid.get(L(-1, 2)), Ok((L(-1, 2), &1)));
+        assert_eq!(grid.get(L(1, 5)), Ok((L(1, 5), &12)));
+        assert!(grid.get(L(2, 2)).is_err());
+
+        assert_eq!(grid.cells().len(), 12);
+
+        for (location, cell) in grid.cells() {
+            assert_eq!(grid.get(location), Ok((location, cell)));
+            assert_eq!(grid.as_ref().get(location), Ok(cell));
+        }
+    }
+
+    #[test]
+    fn test_clamp_edge() {
+        let grid = ClampEdge::new(Translate::new(make_grid(), Rows(-1) + Columns(2)));
+
+        // Every in-bounds location reads through
+        for row in -1..2 {
+            for column in 2..6 {
+                assert_eq!(grid.get(L(row, column)), grid.as_ref().get(L(row, column)));
+            }
+        }
+
+        // Edges
+        assert_eq!(grid.get(L(-100, 3)), Ok(&2));
+        assert_eq!(grid.get(L(100, 3)), Ok(&10));
+        assert_eq!(grid.get(L(0, -100)), Ok(&5));
+        assert_eq!(grid.get(L(0, 100)), Ok(&8));
+
+        // Corners
+        assert_eq!(grid.get(L(-100, -100)), Ok(&1));
+        assert_eq!(grid.get(L(-100, 100)), Ok(&4));
+        assert_eq!(grid.get(L(100, -100)), Ok(&9));
+        assert_eq!(grid.get(L(100, 100)), Ok(&12));
+
+        let empty: VecGrid<i32> = VecGrid::new(Rows(0) + Columns(0)).unwrap();
+        assert!(ClampEdge::new(empty).get(L(0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Map::new(Translate::new(make_grid(), Rows(2) + Columns(2)), |&cell| {
+            cell * 10
+        });
+
+        assert_eq!(grid.get(L(2, 2)), Ok(10));
+        assert_eq!(grid.get(L(4, 5)), Ok(120));
+        assert!(grid.get(L(0, 0)).is_err());
+
+        assert_eq!(grid.cells().len(), 12);
+
+        for (location, cell) in grid.cells() {
+            assert_eq!(
+                grid.as_ref().get(location).map(|&inner| inner * 10),
+                Ok(cell)
+            );
+        }
+    }
+
+    #[test]
+    fn test_map_write() {
+        let mut grid = MapWrite::new(
+            Translate::new(make_grid(), Rows(1) + Columns(1)),
+            |value: &str| value.len() as i32,
+        );
+
+        grid.set(L(1, 1), "hello").unwrap();
+        assert_eq!(grid.get(L(1, 1)), Ok(&5));
+        assert_eq!(grid.as_ref().as_ref()[L(0, 0)], 5);
+
+        assert_eq!(grid.replace(L(3, 4), "ab"), Ok(12));
+        assert_eq!(grid.get(L(3, 4)), Ok(&2));
+
+        assert!(grid.set(L(0, 0), "oops").is_err());
+        assert!(grid.replace(L(4, 1), "oops").is_err());
+        assert_eq!(grid.get(L(1, 2)), Ok(&2));
+    }
+
+    #[test]
+    fn test_zip() {
+        let first = make_grid();
+        let second = Translate::new(Transpose::new(make_grid()), Rows(1) + Columns(1));
+
+        let zipped = Zip::new(&first, &second);
+
+        assert_eq!(zipped.root(), L(1, 1));
+        assert_eq!(zipped.dimensions(), Rows(2) + Columns(3));
+
+        assert_eq!(zipped.get(L(1, 1)), Ok((&6, &1)));
+        assert_eq!(zipped.get(L(2, 3)), Ok((&12, &10)));
+        assert!(zipped.get(L(0, 0)).is_err());
+        assert!(zipped.get(L(3, 1)).is_err());
+
+        assert_eq!(zipped.zip_locations().len(), 6);
+
+        for (location, a, b) in zipped.zip_locations() {
+            assert_eq!(zipped.get(location), Ok((a, b)));
+        }
+
+        let disjoint = Translate::new(make_grid(), Rows(10) + Columns(0));
+        let zipped = Zip::new(&first, &disjoint);
+
+        assert_eq!(zipped.dimensions(), Rows(0) + Columns(4));
+        assert_eq!(zipped.zip_locations().count(), 0);
+    }
+
+    #[test]
+    fn test_transpose_to_vec_grid() {
+        use gridly_grids::IntoVecGrid;
+
+        let grid = Transpose::new(make_grid());
+        let dense = grid.to_vec_grid();
+
+        assert_eq!(dense.dimensions(), Rows(4) + Columns(3));
+        assert_eq!(dense[(0, 0)], 1);
+        assert_eq!(dense[(0, 2)], 9);
+        assert_eq!(dense[(3, 0)], 4);
+        assert_eq!(dense[(2, 1)], 7);
+        assert!(dense.content_eq(&grid));
+    }
+
+    #[test]
+    fn test_transpose_cells_row_major() {
+        let grid = Transpose::new(make_grid());
+
+        let mut cells = grid.cells();
+        assert_eq!(cells.size_hint(), (12, Some(12)));
+        assert_eq!(cells.next(), Some(&1));
+        assert_eq!(cells.next(), Some(&5));
+        assert_eq!(cells.next(), Some(&9));
+        assert_eq!(cells.next(), Some(&2));
+        assert_eq!(cells.next_back(), Some(&12));
+        assert_eq!(cells.size_hint(), (7, Some(7)));
+
+        for (location, cell) in grid.iter_with_locations() {
+            assert_eq!(grid.get(location), Ok(cell));
+        }
+
+        let mut locations = grid.iter_with_locations().map(|(location, _)| location);
+        assert_eq!(locations.next(), Some(L(0, 0)));
+        assert_eq!(locations.next(), Some(L(0, 1)));
+        assert_eq!(locations.next(), Some(L(0, 2)));
+        assert_eq!(locations.next(), Some(L(1, 0)));
+        assert_eq!(locations.next_back(), Some(L(3, 2)));
+    }
+}
@@ -0,0 +1,103 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use gridly::prelude::*;
+use gridly_grids::VecGrid;
+
+/// A deliberately expensive per-cell transform, so that the cost of each row
+/// dominates the cost of distributing rows across threads.
+fn expensive(row: Row, cell: &mut u64) {
+    for i in 0..64 {
+        *cell = cell
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(row.0 as u64 ^ i);
+    }
+}
+
+fn make_grid() -> VecGrid<u64> {
+    VecGrid::new_with((Rows(512), Columns(512)), |loc: Location| {
+        (loc.row.0 * 512 + loc.column.0) as u64
+    })
+    .unwrap()
+}
+
+fn bench_rows_mut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rows_mut expensive transform");
+    let mut grid = make_grid();
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            grid.rows_mut()
+                .for_each(|(row, cells)| cells.iter_mut().for_each(|cell| expensive(row, cell)));
+            black_box(&grid);
+        })
+    });
+
+    #[cfg(feature = "rayon")]
+    group.bench_function("parallel", |b| {
+        use rayon::prelude::*;
+
+        b.iter(|| {
+            grid.par_rows_mut()
+                .for_each(|(row, cells)| cells.iter_mut().for_each(|cell| expensive(row, cell)));
+            black_box(&grid);
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_paste(c: &mut Criterion) {
+    let mut group = c.benchmark_group("paste 256x256 stamp");
+    let mut grid = make_grid();
+    let stamp = VecGrid::new_fill((Rows(256), Columns(256)), &7).unwrap();
+
+    group.bench_function("paste", |b| {
+        b.iter(|| {
+            grid.paste(black_box((100, 100)), &stamp).unwrap();
+            black_box(&grid);
+        })
+    });
+
+    group.bench_function("per-cell set", |b| {
+        b.iter(|| {
+            let offset = black_box(Location::new(100, 100)) - Location::zero();
+
+            for row in stamp.rows().iter() {
+                for (location, &cell) in row.iter_with_locations() {
+                    grid.set(location + offset, cell).unwrap();
+                }
+            }
+            black_box(&grid);
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_touching_counts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("touching counts 512x512");
+    let grid = make_grid().map(|cell| cell % 3 == 0);
+
+    group.bench_function("touching_counts", |b| {
+        b.iter(|| black_box(grid.touching_counts(|&alive| alive)))
+    });
+
+    group.bench_function("per-cell neighbors", |b| {
+        b.iter(|| {
+            black_box(
+                VecGrid::new_with(grid.dimensions(), |location: Location| {
+                    TOUCHING_ADJACENCIES
+                        .iter()
+                        .filter(|&offset| grid.get(location + offset) == Ok(&true))
+                        .count() as u8
+                })
+                .unwrap(),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_rows_mut, bench_paste, bench_touching_counts);
+criterion_main!(benches);
@@ -0,0 +1,613 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+use gridly::prelude::*;
+
+use crate::{SparseGrid, VecGrid};
+
+/// The set of neighbors considered adjacent to a cell, for algorithms like
+/// [`connected_components`][GridExt::connected_components].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Cells are connected to the 4 cells directly above, below, left, and
+    /// right of them.
+    Orthogonal,
+
+    /// Cells are connected to the 8 cells surrounding them, including the
+    /// diagonals.
+    EightWay,
+}
+
+impl Connectivity {
+    /// Get the offsets of the neighbors of a cell with this connectivity.
+    #[must_use]
+    pub fn offsets(self) -> &'static [Vector] {
+        match self {
+            Connectivity::Orthogonal => &ORTHOGONAL_ADJACENCIES,
+            Connectivity::EightWay => &TOUCHING_ADJACENCIES,
+        }
+    }
+}
+
+/// Extension methods for [`Grid`] types that produce new grids from the
+/// [gridly_grids][crate] crate. This trait is implemented for all grids; bring
+/// it into scope to use its methods.
+pub trait GridExt: Grid {
+    /// Crop the grid down to the smallest rectangle that contains all of the
+    /// non-blank cells, as determined by `is_blank`. Returns a new, zero-rooted
+    /// [`VecGrid`] containing clones of the cells in that rectangle, or `None`
+    /// if every cell in the grid is blank.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     vec!['.', '.', '.', '.'],
+    ///     vec!['.', 'a', 'b', '.'],
+    ///     vec!['.', '.', 'c', '.'],
+    ///     vec!['.', '.', '.', '.'],
+    /// ]).unwrap();
+    ///
+    /// let trimmed = grid.trim(|&cell| cell == '.').unwrap();
+    ///
+    /// assert_eq!(trimmed.root(), (0, 0));
+    /// assert_eq!(trimmed.dimensions(), (2, 2));
+    /// assert_eq!(trimmed[(0, 0)], 'a');
+    /// assert_eq!(trimmed[(0, 1)], 'b');
+    /// assert_eq!(trimmed[(1, 0)], '.');
+    /// assert_eq!(trimmed[(1, 1)], 'c');
+    ///
+    /// assert!(grid.trim(|_| true).is_none());
+    /// ```
+    fn trim<F: Fn(&Self::Item) -> bool>(&self, is_blank: F) -> Option<VecGrid<Self::Item>>
+    where
+        Self::Item: Clone,
+    {
+        let mut bounds: Option<(Location, Location)> = None;
+
+        for row in self.rows().iter() {
+            for (location, cell) in row.iter_with_locations() {
+                if !is_blank(cell) {
+                    bounds = Some(match bounds {
+                        None => (location, location),
+                        Some((min, max)) => (
+                            Location::new(
+                                min.row.min(location.row),
+                                min.column.min(location.column),
+                            ),
+                            Location::new(
+                                max.row.max(location.row),
+                                max.column.max(location.column),
+                            ),
+                        ),
+                    });
+                }
+            }
+        }
+
+        let (min, max) = bounds?;
+        let offset = min - Location::zero();
+
+        VecGrid::new_with((max - min) + Vector::new(1, 1), move |location| {
+            // Safety: every location in the new grid is offset into the
+            // bounding box of the non-blank cells, which we found by iterating
+            // over the bounds-checked locations of this grid.
+            unsafe { self.get_unchecked(location + offset) }.clone()
+        })
+    }
+
+    /// Create a new [`VecGrid`] with the same dimensions as this grid, by
+    /// applying `func` to each cell of this grid, along with its location.
+    /// Cells are visited in row-major order. Note that the new grid is always
+    /// rooted at `(0, 0)`, but the locations passed to `func` are locations in
+    /// this grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{GridExt, SparseGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = SparseGrid::new_rooted_default((-1, -1), (Rows(3), Columns(3)), 0);
+    /// grid.set((0, 0), 10).unwrap();
+    ///
+    /// // Add each cell's distance from the center
+    /// let field = grid.map_with_location(|location, &cell| {
+    ///     cell + location.abs_diff(Location::zero()).manhattan_length()
+    /// });
+    ///
+    /// assert_eq!(field.dimensions(), grid.dimensions());
+    /// assert_eq!(field.root(), (0, 0));
+    /// assert_eq!(field[(0, 0)], 2);
+    /// assert_eq!(field[(0, 1)], 1);
+    /// assert_eq!(field[(1, 1)], 10);
+    /// assert_eq!(field[(2, 1)], 1);
+    /// ```
+    fn map_with_location<U>(&self, mut func: impl FnMut(Location, &Self::Item) -> U) -> VecGrid<U> {
+        let cells = self
+            .rows()
+            .iter()
+            .flat_map(|row| row.iter_with_locations())
+            .map(move |(location, cell)| func(location, cell));
+
+        VecGrid::new_row_major(self.dimensions(), cells)
+            .expect("a grid's dimensions and cell count always match")
+    }
+
+    /// Label each cell of the grid with the connected component it belongs to.
+    /// A connected component is a maximal region of equal cells, where each
+    /// cell in the region can be reached from any other by moving between
+    /// adjacent equal cells, with adjacency determined by `connectivity`.
+    ///
+    /// The labels are numbered from 0, in the row-major order of the first
+    /// cell of each component. The result is a zero-rooted [`VecGrid`] with
+    /// the same dimensions as this grid; the total number of components is
+    /// one more than the largest label (or 0 for an empty grid).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{Connectivity, GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#..#".chars(),
+    ///     "#.#.".chars(),
+    /// ]).unwrap();
+    ///
+    /// let labels = grid.labeled_components(Connectivity::Orthogonal);
+    ///
+    /// assert_eq!(labels.display_with(|l| *l).to_string(), "0112\n0134\n");
+    ///
+    /// let labels = grid.labeled_components(Connectivity::EightWay);
+    ///
+    /// assert_eq!(labels.display_with(|l| *l).to_string(), "0112\n0121\n");
+    /// ```
+    fn labeled_components(&self, connectivity: Connectivity) -> VecGrid<usize>
+    where
+        Self::Item: PartialEq,
+    {
+        let root = self.root();
+        let num_columns = self.num_columns().0 as usize;
+        let index_of = |location: Location| {
+            let offset = location - root;
+            offset.rows.0 as usize * num_columns + offset.columns.0 as usize
+        };
+
+        let num_rows = self.num_rows().0 as usize;
+        let mut labels: Vec<Option<usize>> = vec![None; num_rows * num_columns];
+
+        let mut next_label = 0;
+        let mut stack = Vec::new();
+
+        for row in self.rows().iter() {
+            for (location, cell) in row.iter_with_locations() {
+                if labels[index_of(location)].is_some() {
+                    continue;
+                }
+
+                labels[index_of(location)] = Some(next_label);
+                stack.push(location);
+
+                while let Some(current) = stack.pop() {
+                    for offset in connectivity.offsets() {
+                        let neighbor = current + offset;
+
+                        if let Ok(neighbor_cell) = self.get(neighbor) {
+                            let label = &mut labels[index_of(neighbor)];
+
+                            if label.is_none() && neighbor_cell == cell {
+                                *label = Some(next_label);
+                                stack.push(neighbor);
+                            }
+                        }
+                    }
+                }
+
+                next_label += 1;
+            }
+        }
+
+        VecGrid::new_row_major(
+            self.dimensions(),
+            labels
+                .into_iter()
+                .map(|label| label.expect("every cell is labeled")),
+        )
+        .expect("a grid's dimensions and cell count always match")
+    }
+
+    /// Count the number of connected components in the grid. A connected
+    /// component is a maximal region of equal cells, where each cell in the
+    /// region can be reached from any other by moving between adjacent equal
+    /// cells, with adjacency determined by `connectivity`. See
+    /// [`labeled_components`][GridExt::labeled_components] to find which
+    /// component each cell belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{Connectivity, GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "##...".chars(),
+    ///     "....#".chars(),
+    ///     "##..#".chars(),
+    /// ]).unwrap();
+    ///
+    /// // Three separate regions of '#', plus a single region of '.'
+    /// assert_eq!(grid.connected_components(Connectivity::Orthogonal), 4);
+    /// assert_eq!(grid.connected_components(Connectivity::EightWay), 4);
+    ///
+    /// let diagonal = VecGrid::new_from_rows_owned(vec![
+    ///     "#.".chars(),
+    ///     ".#".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(diagonal.connected_components(Connectivity::Orthogonal), 4);
+    /// assert_eq!(diagonal.connected_components(Connectivity::EightWay), 2);
+    /// ```
+    fn connected_components(&self, connectivity: Connectivity) -> usize
+    where
+        Self::Item: PartialEq,
+    {
+        let labels = self.labeled_components(connectivity);
+
+        labels
+            .rows()
+            .iter()
+            .flat_map(|row| row.iter().copied())
+            .max()
+            .map_or(0, |max| max + 1)
+    }
+
+    /// Count the connected components of each distinct value in the grid. The
+    /// result maps each value to the number of separate regions of that
+    /// value, such as the number of separate lakes or forests on a map. See
+    /// [`connected_components`][GridExt::connected_components] for the
+    /// definition of a connected component; the values of the result sum to
+    /// the total number of connected components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{Connectivity, GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "AAB".chars(),
+    ///     "BBB".chars(),
+    ///     "BAA".chars(),
+    /// ]).unwrap();
+    ///
+    /// let counts = grid.component_counts(Connectivity::Orthogonal);
+    ///
+    /// assert_eq!(counts.len(), 2);
+    /// assert_eq!(counts[&'A'], 2);
+    /// assert_eq!(counts[&'B'], 1);
+    /// ```
+    fn component_counts(&self, connectivity: Connectivity) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash + Clone,
+    {
+        let labels = self.labeled_components(connectivity);
+        let mut counts = HashMap::new();
+
+        // Labels are assigned in the row-major order of the first cell of
+        // each component, so a component is new exactly when its label is
+        // the next one we haven't seen yet.
+        let mut next_label = 0;
+
+        for row in self.rows().iter() {
+            for (location, cell) in row.iter_with_locations() {
+                if labels[Location::zero() + self.to_relative(location)] == next_label {
+                    *counts.entry(cell.clone()).or_insert(0) += 1;
+                    next_label += 1;
+                }
+            }
+        }
+
+        counts
+    }
+
+    /// Compute the difference between this grid and a `baseline` grid. The
+    /// result is a [`SparseGrid`] with the same root and dimensions as this
+    /// grid, where each cell that differs from the cell at the same location
+    /// in `baseline` is `Some(cell)`, and every other cell is `None`. Cells
+    /// that are out of bounds in `baseline` are always considered different.
+    ///
+    /// The cells in the diff are wrapped in `Option` so that a cell that
+    /// changed *to* a default-like value is still recorded in the diff. Writing
+    /// each of the diff's occupied entries onto the baseline reconstructs this
+    /// grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let baseline = VecGrid::new_row_major((Rows(3), Columns(3)), 1..).unwrap();
+    ///
+    /// let mut current = baseline.clone();
+    /// current[(0, 1)] = 0;
+    /// current[(2, 2)] = 20;
+    ///
+    /// let diff = current.diff_against(&baseline);
+    ///
+    /// assert_eq!(diff.occupied_entries().count(), 2);
+    /// assert_eq!(diff[(0, 1)], Some(0));
+    /// assert_eq!(diff[(2, 2)], Some(20));
+    /// assert_eq!(diff[(1, 1)], None);
+    ///
+    /// // Apply the diff to the baseline to reconstruct the current grid
+    /// let mut reconstructed = baseline.clone();
+    /// for (location, cell) in diff.occupied_entries() {
+    ///     reconstructed[location] = cell.unwrap();
+    /// }
+    ///
+    /// assert!(reconstructed.content_eq(&current));
+    /// ```
+    fn diff_against<G>(&self, baseline: &G) -> SparseGrid<Option<Self::Item>>
+    where
+        G: Grid<Item = Self::Item> + ?Sized,
+        Self::Item: Clone + PartialEq,
+    {
+        let mut diff = SparseGrid::new_rooted(self.root(), self.dimensions());
+
+        for row in self.rows().iter() {
+            for (location, cell) in row.iter_with_locations() {
+                if baseline.get(location) != Ok(cell) {
+                    // Safety: location came from iterating over this grid,
+                    // and the diff has the same bounds as this grid.
+                    unsafe { diff.set_unchecked(location, Some(cell.clone())) }
+                }
+            }
+        }
+
+        diff
+    }
+
+    /// Get an iterator over the locations in the connected region containing
+    /// `start`. The region is every location that can be reached from `start`
+    /// by moving between adjacent cells equal to the cell at `start`, with
+    /// adjacency determined by `connectivity`. The grid isn't modified, and
+    /// the region is discovered lazily, as the iterator is advanced.
+    ///
+    /// The locations are produced in an unspecified order, starting with
+    /// `start` itself. If `start` is out of bounds, the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{Connectivity, GridExt, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "##...".chars(),
+    ///     ".#..#".chars(),
+    ///     "##.##".chars(),
+    /// ]).unwrap();
+    ///
+    /// let mut blob: Vec<Location> = grid.connected_region((0, 0), Connectivity::Orthogonal).collect();
+    /// gridly::location::sort_row_major(&mut blob);
+    ///
+    /// assert_eq!(blob, [
+    ///     Location::new(0, 0),
+    ///     Location::new(0, 1),
+    ///     Location::new(1, 1),
+    ///     Location::new(2, 0),
+    ///     Location::new(2, 1),
+    /// ]);
+    ///
+    /// // The region stops at differing cells
+    /// assert_eq!(grid.connected_region((0, 2), Connectivity::Orthogonal).count(), 6);
+    /// assert_eq!(grid.connected_region((1, 4), Connectivity::Orthogonal).count(), 3);
+    /// assert_eq!(grid.connected_region((1, 4), Connectivity::EightWay).count(), 3);
+    /// assert_eq!(grid.connected_region((1, 0), Connectivity::EightWay).count(), 1);
+    ///
+    /// assert_eq!(grid.connected_region((5, 5), Connectivity::Orthogonal).count(), 0);
+    /// ```
+    fn connected_region(
+        &self,
+        start: impl LocationLike,
+        connectivity: Connectivity,
+    ) -> ConnectedRegion<'_, Self>
+    where
+        Self::Item: PartialEq,
+    {
+        let mut visited: VecGrid<bool> =
+            VecGrid::new(self.dimensions()).expect("a grid's dimensions are always valid");
+
+        let (value, stack) = match self.get(&start) {
+            Ok(value) => {
+                visited[Location::zero() + self.to_relative(&start)] = true;
+                (Some(value), vec![start.as_location()])
+            }
+            Err(_) => (None, Vec::new()),
+        };
+
+        ConnectedRegion {
+            grid: self,
+            connectivity,
+            value,
+            visited,
+            stack,
+        }
+    }
+}
+
+impl<G: Grid + ?Sized> GridExt for G {}
+
+/// An iterator over the locations in a connected region of a grid. See
+/// [`GridExt::connected_region`] for details.
+#[derive(Debug)]
+pub struct ConnectedRegion<'a, G: Grid + ?Sized> {
+    grid: &'a G,
+    connectivity: Connectivity,
+
+    // The value of the cells in the region, or None if the start location was
+    // out of bounds.
+    value: Option<&'a G::Item>,
+
+    // Locations that have been added to the stack, relative to the root of
+    // the grid.
+    visited: VecGrid<bool>,
+    stack: Vec<Location>,
+}
+
+impl<'a, G> Iterator for ConnectedRegion<'a, G>
+where
+    G: Grid + ?Sized,
+    G::Item: PartialEq,
+{
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        let location = self.stack.pop()?;
+        let value = self.value?;
+
+        for offset in self.connectivity.offsets() {
+            let neighbor = location + offset;
+
+            if let Ok(cell) = self.grid.get(neighbor) {
+                let seen = &mut self.visited[Location::zero() + self.grid.to_relative(neighbor)];
+
+                if !*seen && cell == value {
+                    *seen = true;
+                    self.stack.push(neighbor);
+                }
+            }
+        }
+
+        Some(location)
+    }
+}
+
+impl<'a, G> FusedIterator for ConnectedRegion<'a, G>
+where
+    G: Grid + ?Sized,
+    G::Item: PartialEq,
+{
+}
+
+/// Extension trait for materializing any [`Grid`] into a [`VecGrid`]. This
+/// lives in gridly_grids, rather than as a method on `Grid`, because gridly
+/// itself can't depend on the grid implementations in this crate. It's
+/// implemented for all grids, so a lazily computed grid, such as a view or an
+/// adapter, can be converted into an owned, dense grid with `to_vec_grid()`.
+pub trait IntoVecGrid: Grid {
+    /// Copy every cell of this grid into a new [`VecGrid`] with the same
+    /// dimensions.
+    ///
+    /// The result is always rooted at `(0, 0)`, so for a grid with a
+    /// different root, the cell at `location` in this grid is at
+    /// `location - self.root()` in the `VecGrid`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{IntoVecGrid, SparseGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let mut sparse = SparseGrid::new_rooted_default((-1, 2), (Rows(2), Columns(3)), 0);
+    /// sparse.set((-1, 3), 5).unwrap();
+    /// sparse.set((0, 4), 7).unwrap();
+    ///
+    /// let dense = sparse.to_vec_grid();
+    ///
+    /// assert_eq!(dense.root(), (0, 0));
+    /// assert_eq!(dense.dimensions(), (2, 3));
+    ///
+    /// // (-1, 3) is shifted by the root, which is (-1, 2)
+    /// assert_eq!(dense[(0, 1)], 5);
+    /// assert_eq!(dense[(1, 2)], 7);
+    /// assert!(dense.content_eq(&sparse));
+    /// ```
+    fn to_vec_grid(&self) -> VecGrid<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        let cells = self.rows().iter().flat_map(|row| row.iter().cloned());
+
+        VecGrid::new_row_major(self.dimensions(), cells)
+            .expect("a grid's dimensions and cell count always match")
+    }
+}
+
+impl<G: Grid + ?Sized> IntoVecGrid for G {}
+
+/// Check if `goal` can be reached from `start` by taking orthogonal steps
+/// through cells of `grid` for which `passable` returns true. Both `start` and
+/// `goal` must themselves be passable. Returns false if either location is out
+/// of bounds.
+///
+/// This performs a depth-first search, which stops as soon as `goal` is
+/// found. It's a lightweight alternative to a full pathfinder when only a
+/// yes or no answer is needed.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::{is_reachable, VecGrid};
+/// use gridly::prelude::*;
+///
+/// let mut maze = VecGrid::new_from_rows_owned(vec![
+///     "..#...".chars(),
+///     ".##.#.".chars(),
+///     "....#.".chars(),
+/// ]).unwrap();
+///
+/// let open = |&cell: &char| cell == '.';
+///
+/// assert!(is_reachable(&maze, Location::new(0, 0), Location::new(2, 5), open));
+/// assert!(is_reachable(&maze, Location::new(0, 0), Location::new(0, 0), open));
+///
+/// // Block the only path
+/// maze[(0, 4)] = '#';
+/// assert!(!is_reachable(&maze, Location::new(0, 0), Location::new(2, 5), open));
+///
+/// // Walls and out of bounds locations are never reachable
+/// assert!(!is_reachable(&maze, Location::new(0, 0), Location::new(0, 2), open));
+/// assert!(!is_reachable(&maze, Location::new(0, 0), Location::new(3, 0), open));
+/// ```
+pub fn is_reachable<G: Grid + ?Sized>(
+    grid: &G,
+    start: Location,
+    goal: Location,
+    passable: impl Fn(&G::Item) -> bool,
+) -> bool {
+    let is_passable = |location| grid.get(location).is_ok_and(&passable);
+
+    if !is_passable(start) || !is_passable(goal) {
+        return false;
+    }
+
+    let mut visited: VecGrid<bool> =
+        VecGrid::new(grid.dimensions()).expect("a grid's dimensions are always valid");
+    let mut stack = vec![start];
+
+    visited[Location::zero() + grid.to_relative(start)] = true;
+
+    while let Some(location) = stack.pop() {
+        if location == goal {
+            return true;
+        }
+
+        for (_, neighbor, cell) in grid.orthogonal_steps(location) {
+            let seen = &mut visited[Location::zero() + grid.to_relative(neighbor)];
+
+            if !*seen && passable(cell) {
+                *seen = true;
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    false
+}
@@ -4,9 +4,45 @@
 //! grids.
 
 mod array_grid;
+mod grid_ext;
+mod location_map;
 mod sparse_grid;
 mod vec_grid;
 
 pub use array_grid::ArrayGrid;
+pub use grid_ext::{is_reachable, ConnectedRegion, Connectivity, GridExt, IntoVecGrid};
+pub use location_map::{LocationMap, LocationSet};
 pub use sparse_grid::SparseGrid;
-pub use vec_grid::VecGrid;
+pub use vec_grid::{
+    DimensionMismatch, NotSquareError, RotateRegionError, RowEntry, VecGrid, VecGridCells,
+};
+
+/// The gridly_grids prelude includes everything in the [gridly prelude],
+/// plus the grid types from this crate, the [`GridExt`] trait, which
+/// provides grid algorithms like trimming, labeling connected components,
+/// and diffing, the [`IntoVecGrid`] trait, and free search functions like
+/// [`is_reachable`]. It doesn't include the more specialized types, like
+/// errors and iterators, which can be imported from the crate root.
+///
+/// [gridly prelude]: gridly::prelude
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::prelude::*;
+///
+/// let grid: VecGrid<char> = VecGrid::new_fill((Rows(2), Columns(3)), &'.').unwrap();
+/// let sparse: SparseGrid<char> = SparseGrid::new_default((Rows(2), Columns(3)), '.');
+///
+/// assert!(grid.content_eq(&sparse));
+/// assert_eq!(grid.connected_components(Connectivity::Orthogonal), 1);
+/// ```
+pub mod prelude {
+    #[doc(no_inline)]
+    pub use gridly::prelude::*;
+
+    #[doc(inline)]
+    pub use crate::{
+        is_reachable, ArrayGrid, Connectivity, GridExt, IntoVecGrid, SparseGrid, VecGrid,
+    };
+}
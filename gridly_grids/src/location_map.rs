@@ -0,0 +1,252 @@
+use std::collections::{hash_map, hash_set, HashMap, HashSet};
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+use gridly::prelude::*;
+
+/// A `HashMap` keyed on [`Location`], which accepts any [`LocationLike`] as
+/// a key. `(isize, isize)` tuples compare equal to their equivalent
+/// `Location`, but they don't hash the same way, so a plain `HashMap` that
+/// mixes key types silently fails to find entries. `LocationMap` normalizes
+/// every key with [`canonical`][LocationLike::canonical] before inserting or
+/// looking it up, so any location-like key reaches the same entry.
+///
+/// `LocationMap` derefs to the underlying `HashMap<Location, T>`, so all of
+/// the read-only `HashMap` methods are available directly.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::LocationMap;
+/// use gridly::prelude::*;
+///
+/// let mut map = LocationMap::new();
+/// map.insert((1, 2), 'a');
+/// map.insert(Location::new(3, 4), 'b');
+///
+/// assert_eq!(map.get(Location::new(1, 2)), Some(&'a'));
+/// assert_eq!(map.get((Row(3), Column(4))), Some(&'b'));
+/// assert_eq!(map.get((0, 0)), None);
+///
+/// assert_eq!(map.insert(Location::new(1, 2), 'c'), Some('a'));
+/// assert_eq!(map.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationMap<T> {
+    storage: HashMap<Location, T>,
+}
+
+impl<T> LocationMap<T> {
+    /// Create a new, empty `LocationMap`
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        LocationMap {
+            storage: HashMap::new(),
+        }
+    }
+
+    /// Insert a value at a location, returning the previous value at that
+    /// location, if any.
+    #[inline]
+    pub fn insert(&mut self, location: impl LocationLike, value: T) -> Option<T> {
+        self.storage.insert(location.canonical(), value)
+    }
+
+    /// Get a reference to the value at a location
+    #[inline]
+    #[must_use]
+    pub fn get(&self, location: impl LocationLike) -> Option<&T> {
+        self.storage.get(&location.canonical())
+    }
+
+    /// Get a mutable reference to the value at a location
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, location: impl LocationLike) -> Option<&mut T> {
+        self.storage.get_mut(&location.canonical())
+    }
+
+    /// Check if there's a value at a location
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, location: impl LocationLike) -> bool {
+        self.storage.contains_key(&location.canonical())
+    }
+
+    /// Remove and return the value at a location, if any.
+    #[inline]
+    pub fn remove(&mut self, location: impl LocationLike) -> Option<T> {
+        self.storage.remove(&location.canonical())
+    }
+
+    /// Get the entry for a location, for in-place manipulation.
+    #[inline]
+    pub fn entry(&mut self, location: impl LocationLike) -> hash_map::Entry<'_, Location, T> {
+        self.storage.entry(location.canonical())
+    }
+
+    /// Get the underlying `HashMap`.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> HashMap<Location, T> {
+        self.storage
+    }
+}
+
+impl<T> Default for LocationMap<T> {
+    #[inline]
+    fn default() -> Self {
+        LocationMap::new()
+    }
+}
+
+impl<T> Deref for LocationMap<T> {
+    type Target = HashMap<Location, T>;
+
+    #[inline]
+    fn deref(&self) -> &HashMap<Location, T> {
+        &self.storage
+    }
+}
+
+impl<T> From<HashMap<Location, T>> for LocationMap<T> {
+    #[inline]
+    fn from(storage: HashMap<Location, T>) -> Self {
+        LocationMap { storage }
+    }
+}
+
+impl<L: LocationLike, T> FromIterator<(L, T)> for LocationMap<T> {
+    fn from_iter<I: IntoIterator<Item = (L, T)>>(iter: I) -> Self {
+        LocationMap {
+            storage: iter
+                .into_iter()
+                .map(|(location, value)| (location.canonical(), value))
+                .collect(),
+        }
+    }
+}
+
+impl<L: LocationLike, T> Extend<(L, T)> for LocationMap<T> {
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        self.storage.extend(
+            iter.into_iter()
+                .map(|(location, value)| (location.canonical(), value)),
+        )
+    }
+}
+
+impl<T> IntoIterator for LocationMap<T> {
+    type Item = (Location, T);
+    type IntoIter = hash_map::IntoIter<Location, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.storage.into_iter()
+    }
+}
+
+/// A `HashSet` of [`Location`], which accepts any [`LocationLike`] as a
+/// member. Like [`LocationMap`], it normalizes every location with
+/// [`canonical`][LocationLike::canonical] before inserting or looking it up,
+/// so that tuples and `Location`s are interchangeable.
+///
+/// `LocationSet` derefs to the underlying `HashSet<Location>`, so all of the
+/// read-only `HashSet` methods are available directly.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::LocationSet;
+/// use gridly::prelude::*;
+///
+/// let mut set: LocationSet = [(0, 0), (1, 2)].iter().collect();
+///
+/// assert!(set.contains(Location::new(1, 2)));
+/// assert!(!set.insert(Location::new(0, 0)));
+/// assert!(set.remove((Row(0), Column(0))));
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocationSet {
+    storage: HashSet<Location>,
+}
+
+impl LocationSet {
+    /// Create a new, empty `LocationSet`
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        LocationSet {
+            storage: HashSet::new(),
+        }
+    }
+
+    /// Add a location to the set. Returns true if it wasn't already present.
+    #[inline]
+    pub fn insert(&mut self, location: impl LocationLike) -> bool {
+        self.storage.insert(location.canonical())
+    }
+
+    /// Check if a location is in the set
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, location: impl LocationLike) -> bool {
+        self.storage.contains(&location.canonical())
+    }
+
+    /// Remove a location from the set. Returns true if it was present.
+    #[inline]
+    pub fn remove(&mut self, location: impl LocationLike) -> bool {
+        self.storage.remove(&location.canonical())
+    }
+
+    /// Get the underlying `HashSet`.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> HashSet<Location> {
+        self.storage
+    }
+}
+
+impl Deref for LocationSet {
+    type Target = HashSet<Location>;
+
+    #[inline]
+    fn deref(&self) -> &HashSet<Location> {
+        &self.storage
+    }
+}
+
+impl From<HashSet<Location>> for LocationSet {
+    #[inline]
+    fn from(storage: HashSet<Location>) -> Self {
+        LocationSet { storage }
+    }
+}
+
+impl<L: LocationLike> FromIterator<L> for LocationSet {
+    fn from_iter<I: IntoIterator<Item = L>>(iter: I) -> Self {
+        LocationSet {
+            storage: iter.into_iter().map(LocationLike::canonical).collect(),
+        }
+    }
+}
+
+impl<L: LocationLike> Extend<L> for LocationSet {
+    fn extend<I: IntoIterator<Item = L>>(&mut self, iter: I) {
+        self.storage
+            .extend(iter.into_iter().map(LocationLike::canonical))
+    }
+}
+
+impl IntoIterator for LocationSet {
+    type Item = Location;
+    type IntoIter = hash_set::IntoIter<Location>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.storage.into_iter()
+    }
+}
@@ -5,6 +5,8 @@ use std::ops::{Index, IndexMut};
 
 use gridly::prelude::*;
 
+use crate::VecGrid;
+
 /// A sparse grid, where most of the cells are some default grid.
 ///
 /// Sparse grids are backed by a hash table and a default value, and all elements
@@ -111,6 +113,163 @@ impl<T: Clone + PartialEq> SparseGrid<T> {
         self.storage.iter_mut()
     }
 
+    /// Get an iterator over all of the occupied (non-default) values in the
+    /// grid, in an arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid: SparseGrid<i32> = SparseGrid::new((Rows(5), Columns(5)));
+    /// grid.set((0, 0), 3).unwrap();
+    /// grid.set((1, 4), 4).unwrap();
+    /// grid.set((3, 2), 5).unwrap();
+    ///
+    /// assert_eq!(grid.occupied_values().sum::<i32>(), 12);
+    /// ```
+    pub fn occupied_values(&self) -> impl FusedIterator<Item = &T> + Clone {
+        let default = &self.default;
+        self.storage.values().filter(move |value| *value != default)
+    }
+
+    /// Get an iterator of mutable references to the occupied (non-default)
+    /// values in the grid, in an arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid: SparseGrid<i32> = SparseGrid::new((Rows(5), Columns(5)));
+    /// grid.set((0, 0), 3).unwrap();
+    /// grid.set((1, 4), 4).unwrap();
+    ///
+    /// grid.occupied_values_mut().for_each(|value| *value *= 10);
+    ///
+    /// assert_eq!(grid[(0, 0)], 30);
+    /// assert_eq!(grid[(1, 4)], 40);
+    /// assert_eq!(grid[(1, 1)], 0);
+    /// ```
+    pub fn occupied_values_mut(&mut self) -> impl FusedIterator<Item = &mut T> {
+        let default = &self.default;
+        self.storage
+            .values_mut()
+            .filter(move |value| value != &default)
+    }
+
+    /// Create a dense [`VecGrid`] containing a copy of an arbitrary region of
+    /// this grid. The region is given by `root` and `dimensions`, and doesn't
+    /// need to be inside the bounds of this grid; any cells outside of its
+    /// bounds read as the default. The returned grid is rooted at `(0, 0)`, so
+    /// its `(0, 0)` is this grid's `root`. Returns `None` if the dimensions are
+    /// invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = SparseGrid::new_default((Rows(3), Columns(3)), '.');
+    /// grid.set((0, 0), 'a').unwrap();
+    /// grid.set((2, 2), 'b').unwrap();
+    ///
+    /// let region = grid.to_vec_grid_region((1, 1), (Rows(3), Columns(2))).unwrap();
+    ///
+    /// assert_eq!(region.dimensions(), (3, 2));
+    /// assert_eq!(region.display_with(|c| *c).to_string(), "..\n.b\n..\n");
+    ///
+    /// let region = grid.to_vec_grid_region((-1, -1), (Rows(2), Columns(2))).unwrap();
+    /// assert_eq!(region.display_with(|c| *c).to_string(), "..\n.a\n");
+    /// ```
+    pub fn to_vec_grid_region(
+        &self,
+        root: impl LocationLike,
+        dimensions: impl VectorLike,
+    ) -> Option<VecGrid<T>> {
+        let root = root.as_location();
+
+        VecGrid::new_with(dimensions, move |location| {
+            // All of the entries in storage are in bounds, so there's no need
+            // to separately bounds check the location
+            self.storage
+                .get(&(root + (location - Location::zero())))
+                .unwrap_or(&self.default)
+                .clone()
+        })
+    }
+
+    /// Get the number of in-bounds cells that are currently at the default
+    /// value. This is the total number of cells in the grid, minus the
+    /// number of [occupied entries](SparseGrid::occupied_entries). If the
+    /// grid has more than `usize::MAX` cells, the total saturates at
+    /// `usize::MAX`, so the result is only a lower bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid: SparseGrid<i32> = SparseGrid::new((Rows(3), Columns(4)));
+    /// assert_eq!(grid.default_cell_count(), 12);
+    ///
+    /// grid.set((0, 0), 1).unwrap();
+    /// grid.set((2, 3), 2).unwrap();
+    /// grid.set((1, 1), 0).unwrap();
+    /// assert_eq!(grid.default_cell_count(), 10);
+    ///
+    /// // Inserting outside the bounds grows the grid
+    /// grid.insert((3, 0), 5);
+    /// assert_eq!(grid.default_cell_count(), 13);
+    ///
+    /// // Grids with more cells than fit in a usize saturate
+    /// grid.insert((isize::MAX - 1, isize::MAX - 1), 1);
+    /// assert_eq!(grid.default_cell_count(), usize::MAX - 4);
+    /// ```
+    pub fn default_cell_count(&self) -> usize {
+        self.cell_count()
+            .saturating_sub(self.occupied_entries().count())
+    }
+
+    /// Get the fraction of in-bounds cells that are occupied (non-default),
+    /// from `0.0` to `1.0`. This can be used to decide if a grid would be
+    /// better stored densely. An empty grid has an occupancy ratio of `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid: SparseGrid<i32> = SparseGrid::new((Rows(2), Columns(4)));
+    /// assert_eq!(grid.occupancy_ratio(), 0.0);
+    ///
+    /// grid.set((0, 0), 1).unwrap();
+    /// grid.set((1, 3), 2).unwrap();
+    /// assert_eq!(grid.occupancy_ratio(), 0.25);
+    ///
+    /// let empty: SparseGrid<i32> = SparseGrid::new((0, 0));
+    /// assert_eq!(empty.occupancy_ratio(), 0.0);
+    /// ```
+    pub fn occupancy_ratio(&self) -> f64 {
+        match self.cell_count() {
+            0 => 0.0,
+            total => self.occupied_entries().count() as f64 / total as f64,
+        }
+    }
+
+    /// The total number of in-bounds cells. Because `insert` can grow the
+    /// bounds arbitrarily, this can exceed `usize::MAX`, in which case it
+    /// saturates.
+    fn cell_count(&self) -> usize {
+        (self.dimensions.rows.0.max(0) as usize)
+            .saturating_mul(self.dimensions.columns.0.max(0) as usize)
+    }
+
     /// Insert a value into this grid at an arbitrary location. If the location
     /// is outside the grid's bounds, the grid's bounds are updated to include
     /// this value. Returns the previous value.
@@ -153,7 +312,68 @@ impl<T: Clone + PartialEq> SparseGrid<T> {
     #[inline]
     pub fn insert(&mut self, location: impl LocationLike, value: T) -> T {
         let location = location.as_location();
+        self.grow_to_include(location);
+
+        // Safety: not really unsafe, because HashMap has no unsafe accessors.
+        // However, we're assured that the dimensions are correct after
+        // growing the grid.
+        unsafe { self.replace_unchecked(location, value) }
+    }
 
+    /// Modify the value at an arbitrary location in this grid with a
+    /// function. If the cell is unoccupied, a clone of the default is
+    /// inserted and passed to `func`; if the cell compares equal to the
+    /// default after `func` returns, it's removed from the underlying hash
+    /// table. Like [`insert`][SparseGrid::insert], if the location is outside
+    /// the grid's bounds, the grid's bounds are updated to include it.
+    ///
+    /// This is especially useful for counting grids, as it performs only a
+    /// single lookup.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut visits: SparseGrid<u32> = SparseGrid::new((0, 0));
+    ///
+    /// visits.modify((1, 1), |count| *count += 1);
+    /// visits.modify((1, 1), |count| *count += 1);
+    /// visits.modify((-1, 2), |count| *count += 1);
+    ///
+    /// assert_eq!(visits[(1, 1)], 2);
+    /// assert_eq!(visits[(-1, 2)], 1);
+    /// assert_eq!(visits.root(), (-1, 0));
+    /// assert_eq!(visits.dimensions(), (3, 3));
+    /// assert_eq!(visits.occupied_entries().count(), 2);
+    ///
+    /// // Cells that revert to the default are removed
+    /// visits.modify((-1, 2), |count| *count -= 1);
+    ///
+    /// assert_eq!(visits[(-1, 2)], 0);
+    /// assert_eq!(visits.occupied_entries().count(), 1);
+    /// ```
+    pub fn modify(&mut self, location: impl LocationLike, func: impl FnOnce(&mut T)) {
+        let location = location.as_location();
+        self.grow_to_include(location);
+
+        let default = &self.default;
+        let cell = self
+            .storage
+            .entry(location)
+            .or_insert_with(move || default.clone());
+
+        func(cell);
+
+        if *cell == *default {
+            self.storage.remove(&location);
+        }
+    }
+
+    /// Update the grid's bounds, if necessary, so that they include
+    /// `location`.
+    fn grow_to_include(&mut self, location: Location) {
         let outer_row = self.root.row + self.dimensions.rows;
         let outer_column = self.root.column + self.dimensions.columns;
 
@@ -172,11 +392,6 @@ impl<T: Clone + PartialEq> SparseGrid<T> {
         } else if location.column >= outer_column {
             self.dimensions.columns = (location.column - self.root.column) + 1;
         }
-
-        // Safety: not really unsafe, because HashMap has no unsafe accessors.
-        // However, we're assured that the dimensions are correct after the
-        // above logic.
-        unsafe { self.replace_unchecked(location, value) }
     }
 }
 
@@ -254,6 +469,33 @@ impl<T: Clone + PartialEq> GridMut for SparseGrid<T> {
     /// Get a mutable reference to a cell in the grid. If this cell is unoccupied,
     /// the default is cloned and inserted into the underlying hash table at this
     /// location.
+    ///
+    /// # Example
+    ///
+    /// Because unoccupied cells are filled in on demand, this is also what
+    /// allows [`swap`][GridMut::swap] to move values into unoccupied cells.
+    ///
+    /// ```
+    /// use gridly_grids::SparseGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = SparseGrid::new_default((Rows(8), Columns(8)), String::new());
+    /// grid.set((7, 7), "a".to_string()).unwrap();
+    ///
+    /// // Walk the value through every cell in the grid, filling in each
+    /// // unoccupied cell as it's reached
+    /// let mut from = Location::new(7, 7);
+    /// for row in 0..8 {
+    ///     for column in 0..8 {
+    ///         grid.swap(from, (row, column)).unwrap();
+    ///         from = Location::new(row, column);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(grid[(7, 7)], "a");
+    /// assert_eq!(grid[(0, 0)], "");
+    /// assert!(grid.swap((0, 0), (8, 0)).is_err());
+    /// ```
     unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut T {
         let default = &self.default;
         self.storage
@@ -269,3 +511,86 @@ impl<T: Clone + PartialEq, L: LocationLike> IndexMut<L> for SparseGrid<T> {
         })
     }
 }
+
+/// Extend a `SparseGrid` with `(location, value)` pairs. Each pair is added
+/// with [`insert`][SparseGrid::insert], which means that the grid's bounds
+/// will grow to include every location in the iterator.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::SparseGrid;
+/// use gridly::prelude::*;
+///
+/// let mut grid: SparseGrid<char> = SparseGrid::new_default((Rows(2), Columns(2)), '.');
+///
+/// grid.extend(vec![
+///     (Location::new(1, 1), 'a'),
+///     (Location::new(-2, 0), 'b'),
+///     (Location::new(3, 4), 'c'),
+/// ]);
+///
+/// assert_eq!(grid.root(), (-2, 0));
+/// assert_eq!(grid.dimensions(), (6, 5));
+///
+/// assert_eq!(grid[(1, 1)], 'a');
+/// assert_eq!(grid[(-2, 0)], 'b');
+/// assert_eq!(grid[(3, 4)], 'c');
+/// assert_eq!(grid[(0, 0)], '.');
+/// ```
+impl<T: Clone + PartialEq, L: LocationLike> Extend<(L, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |(location, value)| {
+            self.insert(location, value);
+        })
+    }
+}
+
+/// Sparse grids are compared by their logical contents: two grids are equal
+/// if they have the same root, dimensions, and default, and the same value at
+/// every location. Differences in the underlying storage that don't affect
+/// the contents, such as an occupied entry that happens to equal the default,
+/// don't cause inequality.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::SparseGrid;
+/// use gridly::prelude::*;
+///
+/// let mut grid1: SparseGrid<char> = SparseGrid::new_default((Rows(2), Columns(2)), '.');
+/// let mut grid2 = grid1.clone();
+///
+/// grid1.insert((1, 1), 'a');
+/// grid2.insert((1, 1), 'a');
+///
+/// // Getting a mutable reference stores a copy of the default in grid2's
+/// // underlying hash table, but doesn't change its contents
+/// *grid2.get_mut((0, 0)).unwrap() = '.';
+/// assert_eq!(grid1, grid2);
+///
+/// grid2.insert((0, 1), 'b');
+/// assert_ne!(grid1, grid2);
+///
+/// let grid3: SparseGrid<char> = SparseGrid::new_default((Rows(2), Columns(2)), '#');
+/// assert_ne!(SparseGrid::new_default((Rows(2), Columns(2)), '.'), grid3);
+/// ```
+impl<T: Clone + PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Each grid's occupied entries must match the other grid's logical
+        // contents; every other location is the default in both grids.
+        fn contents_match<T: Clone + PartialEq>(lhs: &SparseGrid<T>, rhs: &SparseGrid<T>) -> bool {
+            lhs.storage
+                .iter()
+                .all(|(location, value)| rhs.storage.get(location).unwrap_or(&rhs.default) == value)
+        }
+
+        self.root == other.root
+            && self.dimensions == other.dimensions
+            && self.default == other.default
+            && contents_match(self, other)
+            && contents_match(other, self)
+    }
+}
+
+impl<T: Clone + Eq> Eq for SparseGrid<T> {}
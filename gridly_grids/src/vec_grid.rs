@@ -1,12 +1,111 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, TryReserveError};
 use std::convert::TryInto;
-use std::iter::repeat_with;
-use std::mem::replace;
-use std::ops::{Index, IndexMut};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::iter::{repeat_with, FusedIterator};
+use std::mem::{replace, swap};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::slice;
 
 use gridly::prelude::*;
+use gridly::range::CrossRange;
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Error returned from operations that require a square grid or region,
+/// such as [`VecGrid::transpose_in_place`], when the grid or region isn't
+/// square.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NotSquareError {
+    /// The dimensions of the grid or region
+    pub dimensions: Vector,
+}
+
+impl Display for NotSquareError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "grid is not square: {} rows and {} columns",
+            self.dimensions.rows.0, self.dimensions.columns.0
+        )
+    }
+}
+
+impl Error for NotSquareError {}
+
+/// Error returned from operations that require two grids to have the same
+/// dimensions, such as [`VecGrid::swap_contents`], when they don't.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DimensionMismatch {
+    /// The dimensions of the grid the operation was called on
+    pub expected: Vector,
+
+    /// The dimensions of the other grid
+    pub found: Vector,
+}
+
+impl Display for DimensionMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "grid dimensions don't match: expected {} rows and {} columns, found {} rows and {} columns",
+            self.expected.rows.0,
+            self.expected.columns.0,
+            self.found.rows.0,
+            self.found.columns.0,
+        )
+    }
+}
+
+impl Error for DimensionMismatch {}
+
+/// Error returned from [`VecGrid::rotate_region`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RotateRegionError {
+    /// The region wasn't entirely inside the bounds of the grid
+    OutOfBounds(BoundsError),
+
+    /// The region needed to be square for the rotation, but wasn't
+    NotSquare(NotSquareError),
+}
+
+impl From<BoundsError> for RotateRegionError {
+    fn from(err: BoundsError) -> Self {
+        RotateRegionError::OutOfBounds(err)
+    }
+}
+
+impl From<NotSquareError> for RotateRegionError {
+    fn from(err: NotSquareError) -> Self {
+        RotateRegionError::NotSquare(err)
+    }
+}
+
+impl Display for RotateRegionError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RotateRegionError::OutOfBounds(err) => write!(f, "region out of bounds: {}", err),
+            RotateRegionError::NotSquare(err) => write!(f, "can't rotate region: {}", err),
+        }
+    }
+}
+
+impl Error for RotateRegionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RotateRegionError::OutOfBounds(_) => None,
+            RotateRegionError::NotSquare(err) => Some(err),
+        }
+    }
+}
 
 /// A grid that stores its elements in a `Vec<T>`, in row-major order.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VecGrid<T> {
     dimensions: Vector,
     storage: Vec<T>,
@@ -39,6 +138,20 @@ impl<T> VecGrid<T> {
         (loc.row.0 as usize * self.dimensions.columns.0 as usize) + loc.column.0 as usize
     }
 
+    /// Check that a rectangular region, described by its `root` and
+    /// `dimensions`, is entirely inside the bounds of this grid. Empty regions
+    /// are always considered in bounds. Otherwise, returns the bounds error
+    /// for whichever corner of the region is out of bounds.
+    fn check_region(&self, root: Location, dimensions: Vector) -> Result<(), BoundsError> {
+        if dimensions.rows <= 0 || dimensions.columns <= 0 {
+            return Ok(());
+        }
+
+        self.check_location(root)?;
+        self.check_location(root + dimensions - Vector::new(1, 1))?;
+        Ok(())
+    }
+
     /// Create a new `VecGrid`, filled with elements by repeatedly calling a
     /// function. The function is called once per cell in an unspecified order;
     /// use [`new_with`][VecGrid::new_with] if you want to have per-cell
@@ -111,6 +224,48 @@ impl<T> VecGrid<T> {
         })
     }
 
+    /// Create a new `VecGrid` by calling a function with the flat, row-major
+    /// index of each cell in the grid, storing the return value of that
+    /// function in that cell. The cell at `(row, column)` has the index
+    /// `row * num_columns + column`. Unlike [`new_with`][VecGrid::new_with],
+    /// the function is guaranteed to be called exactly once per cell, in
+    /// order, with each index from `0` up to the number of cells. This is
+    /// useful when the source of the grid's data is inherently linear.
+    ///
+    /// Returns the grid, or `None` if the `dimensions` were invalid.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let data = "abcdef";
+    /// let grid = VecGrid::new_from_index((Rows(2), Columns(3)), |index| {
+    ///     data.as_bytes()[index] as char
+    /// }).unwrap();
+    ///
+    /// assert_eq!(grid[(0, 0)], 'a');
+    /// assert_eq!(grid[(0, 2)], 'c');
+    /// assert_eq!(grid[(1, 0)], 'd');
+    /// assert_eq!(grid[(1, 2)], 'f');
+    ///
+    /// assert!(VecGrid::new_from_index((Rows(-1), Columns(3)), |index| index).is_none());
+    /// ```
+    pub fn new_from_index(
+        dimensions: impl VectorLike,
+        gen: impl FnMut(usize) -> T,
+    ) -> Option<Self> {
+        let dimensions = dimensions.as_vector();
+        let volume = Self::get_volume(&dimensions)?;
+        let storage = (0..volume).map(gen).collect();
+
+        Some(VecGrid {
+            dimensions,
+            storage,
+        })
+    }
+
     /// Create a new `VecGrid` with the given dimensions. Fills the grid
     /// in row-major order (that is, by filling the first row, then the next
     /// row, etc) by evaluating the input iterator. Returns None if
@@ -183,6 +338,11 @@ impl<T> VecGrid<T> {
     /// If the number of columns is mismatched between any two rows. If the
     /// rows iterator is empty, the returned dimensions are (0, 0).
     ///
+    /// Note that the cells are taken from the rows by value, so passing rows
+    /// by reference (for instance, `&Vec<Vec<T>>`) produces a grid of
+    /// references. Pass the rows by value to move the cells into the grid;
+    /// see also [`new_from_rows_owned`][VecGrid::new_from_rows_owned].
+    ///
     /// # Examples
     ///
     /// ## Basic example
@@ -197,7 +357,26 @@ impl<T> VecGrid<T> {
     ///     vec![7, 8, 9],
     /// ];
     ///
-    /// let grid = VecGrid::new_from_rows(&rows).unwrap();
+    /// let grid: VecGrid<i32> = VecGrid::new_from_rows(rows).unwrap();
+    ///
+    /// assert_eq!(grid[(0, 0)], 1);
+    /// assert_eq!(grid[(1, 1)], 5);
+    /// assert_eq!(grid[(2, 2)], 9);
+    /// ```
+    ///
+    /// ## Borrowed rows example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let rows = vec![
+    ///     vec![1, 2, 3],
+    ///     vec![4, 5, 6],
+    ///     vec![7, 8, 9],
+    /// ];
+    ///
+    /// let grid: VecGrid<&i32> = VecGrid::new_from_rows(&rows).unwrap();
     ///
     /// assert_eq!(grid[(0, 0)], &1);
     /// assert_eq!(grid[(1, 1)], &5);
@@ -299,6 +478,40 @@ impl<T> VecGrid<T> {
         }
     }
 
+    /// Create a new `VecGrid` from owned rows, such as a `Vec<Vec<T>>`, moving
+    /// each cell into the grid. This is identical to
+    /// [`new_from_rows`][VecGrid::new_from_rows], but is restricted to rows
+    /// that yield `T` by value, which avoids accidentally creating a grid of
+    /// references. Returns `None` if the number of columns is mismatched
+    /// between any two rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let rows = vec![vec![1, 2], vec![3, 4]];
+    /// let grid: VecGrid<i32> = VecGrid::new_from_rows_owned(rows).unwrap();
+    ///
+    /// assert_eq!(grid.dimensions(), (2, 2));
+    /// assert_eq!(grid[(0, 0)], 1);
+    /// assert_eq!(grid[(0, 1)], 2);
+    /// assert_eq!(grid[(1, 0)], 3);
+    /// assert_eq!(grid[(1, 1)], 4);
+    ///
+    /// // Works with non-Copy cells, too
+    /// let rows = vec![vec!["a".to_string()], vec!["b".to_string()]];
+    /// let grid: VecGrid<String> = VecGrid::new_from_rows_owned(rows).unwrap();
+    /// assert_eq!(grid[(1, 0)], "b");
+    /// ```
+    #[inline]
+    pub fn new_from_rows_owned<C: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = C>,
+    ) -> Option<Self> {
+        Self::new_from_rows(rows)
+    }
+
     /// Fill every cell in the grid with the values produced by repeatedly
     /// calling `gen`. Called in an unspecified order.
     ///
@@ -361,6 +574,451 @@ impl<T> VecGrid<T> {
             .zip(&mut self.storage)
             .for_each(|(item, cell)| *cell = item);
     }
+
+    /// Get the number of cells the grid's underlying storage can hold without
+    /// reallocating. This is always at least the volume of the grid.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+
+    /// Shrink the capacity of the grid's underlying storage as much as
+    /// possible. Constructors like [`new_from_rows`][VecGrid::new_from_rows]
+    /// grow the storage incrementally, which can leave excess capacity
+    /// behind; this releases it, which is useful for large, long-lived grids.
+    /// Like [`Vec::shrink_to_fit`], the allocator may still keep some excess.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// // Filtering hides the row lengths from the size hint, so the storage
+    /// // may over-allocate as it grows
+    /// let rows = (0..3).map(|row| (0..4).map(move |col| row * 4 + col).filter(|_| true));
+    /// let mut grid = VecGrid::new_from_rows(rows).unwrap();
+    /// let capacity = grid.capacity();
+    ///
+    /// grid.shrink_to_fit();
+    /// assert!(grid.capacity() <= capacity);
+    /// assert!(grid.capacity() >= 12);
+    /// assert_eq!(grid.dimensions(), (Rows(3), Columns(4)));
+    /// assert_eq!(grid[(2, 3)], 11);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.storage.shrink_to_fit()
+    }
+
+    /// Swap the contents of this grid with the contents of `other`, which
+    /// must have the same dimensions. This swaps the underlying storage, so
+    /// it's O(1) and doesn't allocate, which makes it a useful primitive for
+    /// double-buffered simulations, like cellular automata, that compute each
+    /// generation into a second grid. Returns an error if the dimensions of
+    /// the grids differ, in which case neither grid is changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::{DimensionMismatch, VecGrid};
+    /// use gridly::prelude::*;
+    ///
+    /// let mut current = VecGrid::new_fill((Rows(2), Columns(2)), &0).unwrap();
+    /// let mut next = VecGrid::new_from_index((Rows(2), Columns(2)), |i| i).unwrap();
+    ///
+    /// assert_eq!(current.swap_contents(&mut next), Ok(()));
+    /// assert_eq!(current, VecGrid::new_from_index((Rows(2), Columns(2)), |i| i).unwrap());
+    /// assert_eq!(next, VecGrid::new_fill((Rows(2), Columns(2)), &0).unwrap());
+    ///
+    /// let mut wide = VecGrid::new_fill((Rows(1), Columns(4)), &9).unwrap();
+    /// assert_eq!(
+    ///     current.swap_contents(&mut wide),
+    ///     Err(DimensionMismatch {
+    ///         expected: Vector::new(2, 2),
+    ///         found: Vector::new(1, 4),
+    ///     })
+    /// );
+    /// assert_eq!(current[(1, 1)], 3);
+    /// assert_eq!(wide[(0, 3)], 9);
+    /// ```
+    pub fn swap_contents(&mut self, other: &mut VecGrid<T>) -> Result<(), DimensionMismatch> {
+        if self.dimensions != other.dimensions {
+            return Err(DimensionMismatch {
+                expected: self.dimensions,
+                found: other.dimensions,
+            });
+        }
+
+        swap(&mut self.storage, &mut other.storage);
+        Ok(())
+    }
+
+    /// Create a new grid with the same dimensions as this one, by applying
+    /// `func` to each cell, in row-major order. This consumes the grid, so
+    /// each cell is moved into `func`; use [`map_ref`][VecGrid::map_ref] to
+    /// keep the original grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(2)), vec![0u8, 1, 1, 0]).unwrap();
+    /// let chars = grid.map(|cell| if cell == 1 { '#' } else { '.' });
+    ///
+    /// assert_eq!(chars.dimensions(), (2, 2));
+    /// assert_eq!(chars[(0, 0)], '.');
+    /// assert_eq!(chars[(0, 1)], '#');
+    /// assert_eq!(chars[(1, 0)], '#');
+    /// assert_eq!(chars[(1, 1)], '.');
+    /// ```
+    pub fn map<U>(self, func: impl FnMut(T) -> U) -> VecGrid<U> {
+        VecGrid {
+            dimensions: self.dimensions,
+            storage: self.storage.into_iter().map(func).collect(),
+        }
+    }
+
+    /// Create a new grid with the same dimensions as this one, by applying
+    /// `func` to a reference to each cell, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     vec!["a", "bb"],
+    ///     vec!["ccc", ""],
+    /// ]).unwrap();
+    ///
+    /// let lengths = grid.map_ref(|cell| cell.len());
+    ///
+    /// assert_eq!(lengths.dimensions(), grid.dimensions());
+    /// assert_eq!(lengths[(0, 1)], 2);
+    /// assert_eq!(lengths[(1, 0)], 3);
+    /// assert_eq!(grid[(1, 0)], "ccc");
+    /// ```
+    pub fn map_ref<U>(&self, func: impl FnMut(&T) -> U) -> VecGrid<U> {
+        VecGrid {
+            dimensions: self.dimensions,
+            storage: self.storage.iter().map(func).collect(),
+        }
+    }
+
+    /// Get an iterator over all the cells in the grid, paired with their
+    /// locations, in row-major order. This is a faster version of
+    /// [`Grid::iter_with_locations`]: because the grid is stored in row-major
+    /// order, this iterates the underlying storage directly, without any
+    /// per-cell bounds checks. Unlike iterating over row views, the returned
+    /// [`VecGridCells`] is a nameable type, so it can be stored in structs or
+    /// returned from functions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(2)), 1..).unwrap();
+    /// let mut cells = grid.iter_with_locations();
+    ///
+    /// assert_eq!(cells.len(), 4);
+    /// assert_eq!(cells.next(), Some((Location::new(0, 0), &1)));
+    /// assert_eq!(cells.next(), Some((Location::new(0, 1), &2)));
+    /// assert_eq!(cells.next_back(), Some((Location::new(1, 1), &4)));
+    /// assert_eq!(cells.next(), Some((Location::new(1, 0), &3)));
+    /// assert_eq!(cells.next(), None);
+    /// ```
+    pub fn iter_with_locations(&self) -> VecGridCells<'_, T> {
+        VecGridCells {
+            locations: CrossRange::new(
+                Row(0).span(self.dimensions.rows),
+                Column(0).span(self.dimensions.columns),
+            ),
+            cells: self.storage.iter(),
+        }
+    }
+
+    /// Get an iterator of mutable references to all the cells in the grid,
+    /// paired with their locations, in row-major order. This allows every
+    /// cell to be updated in place based on its location, without any
+    /// per-cell bounds checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_fill((Rows(2), Columns(3)), &1).unwrap();
+    ///
+    /// for (location, cell) in grid.iter_mut_with_locations() {
+    ///     *cell += location.row.0 * 10 + location.column.0;
+    /// }
+    ///
+    /// assert_eq!(grid[(0, 0)], 1);
+    /// assert_eq!(grid[(0, 2)], 3);
+    /// assert_eq!(grid[(1, 0)], 11);
+    /// assert_eq!(grid[(1, 2)], 13);
+    /// ```
+    pub fn iter_mut_with_locations(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (Location, &mut T)> + ExactSizeIterator + FusedIterator
+    {
+        CrossRange::new(
+            Row(0).span(self.dimensions.rows),
+            Column(0).span(self.dimensions.columns),
+        )
+        .zip(self.storage.iter_mut())
+    }
+
+    /// Count, for each cell, how many of its 8 touching neighbors satisfy
+    /// `pred`. The result is a grid with the same dimensions as this one;
+    /// cells on the edge of the grid only count their in-bounds neighbors.
+    /// This is the neighbor count used to step cellular automata like
+    /// Conway's Game of Life.
+    ///
+    /// Rather than checking the neighbors of each cell separately, this
+    /// evaluates `pred` once per cell, in a single pass over the underlying
+    /// storage, and adds each matching cell to the counts of its neighbors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// // A horizontal blinker
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     ".....".chars(),
+    ///     ".###.".chars(),
+    ///     ".....".chars(),
+    /// ]).unwrap();
+    ///
+    /// let counts = grid.touching_counts(|&cell| cell == '#');
+    ///
+    /// let expected = VecGrid::new_row_major((Rows(3), Columns(5)), vec![
+    ///     1, 2, 3, 2, 1,
+    ///     1, 1, 2, 1, 1,
+    ///     1, 2, 3, 2, 1,
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(counts, expected);
+    /// ```
+    pub fn touching_counts<F: Fn(&T) -> bool>(&self, pred: F) -> VecGrid<u8> {
+        let num_rows = self.dimensions.rows.0.max(0) as usize;
+        let num_columns = self.dimensions.columns.0.max(0) as usize;
+        let mut counts = vec![0; self.storage.len()];
+
+        for (index, cell) in self.storage.iter().enumerate() {
+            if !pred(cell) {
+                continue;
+            }
+
+            let row = index / num_columns;
+            let column = index % num_columns;
+
+            for neighbor_row in row.saturating_sub(1)..(row + 2).min(num_rows) {
+                for neighbor_column in column.saturating_sub(1)..(column + 2).min(num_columns) {
+                    if (neighbor_row, neighbor_column) != (row, column) {
+                        counts[neighbor_row * num_columns + neighbor_column] += 1;
+                    }
+                }
+            }
+        }
+
+        VecGrid {
+            dimensions: self.dimensions,
+            storage: counts,
+        }
+    }
+
+    /// Get an iterator over slices of each row in the grid, paired with the
+    /// index of that row. This is the `VecGrid` equivalent of
+    /// [`Grid::scanlines`]; because the grid is stored in row-major order, each
+    /// row can be provided as a contiguous slice, rather than as a
+    /// [`RowView`][gridly::grid::RowView]. A grid with zero columns yields no
+    /// rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    /// let mut scanlines = grid.scanline_slices();
+    ///
+    /// assert_eq!(scanlines.next(), Some((Row(0), &[1, 2, 3][..])));
+    /// assert_eq!(scanlines.next(), Some((Row(1), &[4, 5, 6][..])));
+    /// assert_eq!(scanlines.next(), None);
+    /// ```
+    pub fn scanline_slices(&self) -> impl Iterator<Item = (Row, &[T])> {
+        let num_columns = self.dimensions.columns.0 as usize;
+
+        Row(0)
+            .span(self.dimensions.rows)
+            .zip(self.storage.chunks(num_columns.max(1)))
+    }
+
+    /// Get an iterator over mutable slices of each row in the grid, paired
+    /// with the index of that row. Because the grid is stored in row-major
+    /// order, each row is a contiguous slice of the underlying storage. A
+    /// grid with zero columns yields no rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_fill((Rows(2), Columns(3)), &1).unwrap();
+    ///
+    /// for (row, cells) in grid.rows_mut() {
+    ///     cells.iter_mut().for_each(|cell| *cell += row.0);
+    /// }
+    ///
+    /// assert_eq!(grid[(0, 2)], 1);
+    /// assert_eq!(grid[(1, 0)], 2);
+    /// assert_eq!(grid[(1, 2)], 2);
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = (Row, &mut [T])> {
+        let num_columns = self.dimensions.columns.0 as usize;
+
+        Row(0)
+            .span(self.dimensions.rows)
+            .zip(self.storage.chunks_mut(num_columns.max(1)))
+    }
+
+    /// Get a bounds-checked, mutable handle to a single row of the grid. The
+    /// returned [`RowEntry`] dereferences to a mutable slice of the row's
+    /// cells, which is possible because the grid is stored in row-major
+    /// order. Returns an error if the row is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    ///
+    /// let mut entry = grid.row_entry(1).unwrap();
+    /// assert_eq!(entry.row(), Row(1));
+    /// assert_eq!(*entry, [4, 5, 6]);
+    ///
+    /// entry.reverse();
+    /// entry[0] *= 10;
+    ///
+    /// assert_eq!(grid[(1, 0)], 60);
+    /// assert_eq!(grid[(1, 2)], 4);
+    /// assert_eq!(grid[(0, 0)], 1);
+    ///
+    /// assert!(grid.row_entry(2).is_err());
+    /// assert!(grid.row_entry(-1).is_err());
+    /// ```
+    pub fn row_entry(&mut self, row: impl Into<Row>) -> Result<RowEntry<'_, T>, RowRangeError> {
+        let row = self.check_row(row)?;
+        let num_columns = self.dimensions.columns.0 as usize;
+        let start = row.0 as usize * num_columns;
+
+        Ok(RowEntry {
+            row,
+            cells: &mut self.storage[start..start + num_columns],
+        })
+    }
+
+    /// Transpose a square grid in place, by swapping each cell `(i, j)` with
+    /// `(j, i)`. This avoids allocating a second grid. Returns an error, and
+    /// leaves the grid unchanged, if the grid isn't square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_row_major((Rows(3), Columns(3)), 1..).unwrap();
+    /// let expected =
+    ///     VecGrid::new_with((Rows(3), Columns(3)), |loc| grid[loc.transpose()]).unwrap();
+    ///
+    /// grid.transpose_in_place().unwrap();
+    /// assert!(grid.content_eq(&expected));
+    /// assert_eq!(grid[(0, 1)], 4);
+    /// assert_eq!(grid[(2, 0)], 3);
+    ///
+    /// let mut grid = VecGrid::new_row_major((Rows(2), Columns(3)), 1..).unwrap();
+    /// let err = grid.transpose_in_place().unwrap_err();
+    /// assert_eq!(err.dimensions, (2, 3));
+    /// assert_eq!(grid[(0, 1)], 2);
+    /// ```
+    pub fn transpose_in_place(&mut self) -> Result<(), NotSquareError> {
+        if self.dimensions.rows.0 != self.dimensions.columns.0 {
+            return Err(NotSquareError {
+                dimensions: self.dimensions,
+            });
+        }
+
+        let size = self.dimensions.rows.0 as usize;
+
+        for i in 0..size {
+            for j in i + 1..size {
+                self.storage.swap(i * size + j, j * size + i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send> VecGrid<T> {
+    /// Get a parallel iterator over mutable slices of each row in the grid,
+    /// paired with the index of that row. This is the parallel equivalent of
+    /// [`rows_mut`][VecGrid::rows_mut]; since the rows are disjoint slices,
+    /// each one can be safely mutated on a separate thread. A grid with zero
+    /// columns yields no rows.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let gen = |loc: Location| loc.row.0 * 10 + loc.column.0;
+    /// let mut parallel = VecGrid::new_with((Rows(50), Columns(20)), gen).unwrap();
+    /// let mut sequential = parallel.clone();
+    ///
+    /// parallel.par_rows_mut().for_each(|(row, cells)| {
+    ///     cells.iter_mut().for_each(|cell| *cell *= row.0)
+    /// });
+    ///
+    /// sequential.rows_mut().for_each(|(row, cells)| {
+    ///     cells.iter_mut().for_each(|cell| *cell *= row.0)
+    /// });
+    ///
+    /// assert_eq!(parallel[(7, 3)], 7 * 73);
+    /// assert!(parallel
+    ///     .rows()
+    ///     .iter()
+    ///     .zip(sequential.rows().iter())
+    ///     .all(|(a, b)| a.iter().eq(b.iter())));
+    /// ```
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = (Row, &mut [T])> {
+        let num_columns = self.dimensions.columns.0 as usize;
+
+        self.storage
+            .par_chunks_mut(num_columns.max(1))
+            .enumerate()
+            .map(|(index, cells)| (Row(index as isize), cells))
+    }
 }
 
 impl<T: Default> VecGrid<T> {
@@ -459,6 +1117,104 @@ impl<T: Clone> VecGrid<T> {
         Self::new_fill_with(dimensions, || value.clone())
     }
 
+    /// Create a new square `VecGrid`, `size` cells on each side, with clones
+    /// of `diagonal` on the main diagonal (the cells where the row and column
+    /// are equal) and clones of `off_diagonal` everywhere else. With a
+    /// `diagonal` of 1 and an `off_diagonal` of 0, this is the identity
+    /// matrix. Returns `None` if `size` is too large.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_diagonal(3, &1, &0).unwrap();
+    ///
+    /// assert_eq!(grid.dimensions(), (3, 3));
+    /// assert_eq!(grid.display_with(|cell| *cell).to_string(), "100\n010\n001\n");
+    /// ```
+    pub fn new_diagonal(size: usize, diagonal: &T, off_diagonal: &T) -> Option<Self> {
+        let size: isize = size.try_into().ok()?;
+
+        Self::new_with((Rows(size), Columns(size)), |location| {
+            if location.row.0 == location.column.0 {
+                diagonal.clone()
+            } else {
+                off_diagonal.clone()
+            }
+        })
+    }
+
+    /// Create a new `VecGrid` filled with a checkerboard pattern of clones of
+    /// `even` and `odd`. Cells where the sum of the row and column is even
+    /// (including the root at `(0, 0)`) are filled with `even`, and the rest
+    /// with `odd`, so that orthogonally adjacent cells always differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_checkerboard((Rows(3), Columns(4)), &'#', &'.').unwrap();
+    ///
+    /// assert_eq!(grid.display_with(|cell| *cell).to_string(), "#.#.\n.#.#\n#.#.\n");
+    /// assert_eq!(grid[(2, 2)], '#');
+    /// assert_eq!(grid[(2, 3)], '.');
+    /// ```
+    pub fn new_checkerboard(dimensions: impl VectorLike, even: &T, odd: &T) -> Option<Self> {
+        Self::new_with(dimensions, |location| {
+            if (location.row.0 + location.column.0) % 2 == 0 {
+                even.clone()
+            } else {
+                odd.clone()
+            }
+        })
+    }
+
+    /// Create a new `VecGrid` filled with clones of `value`, reporting
+    /// allocation failure as an error instead of aborting. This is the
+    /// fallible equivalent of [`new_fill`][VecGrid::new_fill], for use with
+    /// potentially enormous dimensions.
+    ///
+    /// Returns `Ok(None)` if the `dimensions` were invalid, or an error if the
+    /// grid's storage couldn't be allocated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::try_new_fill((Rows(2), Columns(3)), &'.').unwrap().unwrap();
+    /// assert_eq!(grid.dimensions(), (2, 3));
+    /// assert_eq!(grid[(1, 2)], '.');
+    ///
+    /// let invalid = VecGrid::try_new_fill((Rows(-2), Columns(3)), &'.').unwrap();
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn try_new_fill(
+        dimensions: impl VectorLike,
+        value: &T,
+    ) -> Result<Option<Self>, TryReserveError> {
+        let dimensions = dimensions.as_vector();
+
+        let volume = match Self::get_volume(&dimensions) {
+            Some(volume) => volume,
+            None => return Ok(None),
+        };
+
+        let mut storage = Vec::new();
+        storage.try_reserve_exact(volume)?;
+        storage.resize(volume, value.clone());
+
+        Ok(Some(VecGrid {
+            dimensions,
+            storage,
+        }))
+    }
+
     /// Fill every element in the grid with clones of `value`.
     ///
     /// # Example
@@ -479,6 +1235,239 @@ impl<T: Clone> VecGrid<T> {
     pub fn fill(&mut self, value: &T) {
         self.fill_with(|| value.clone())
     }
+
+    /// Copy the contents of `source` into this grid, such that the root of
+    /// `source` is placed at `at`. The copy is done a row at a time, with
+    /// [`clone_from_slice`][slice::clone_from_slice], which makes this much
+    /// faster than copying cell by cell.
+    ///
+    /// The `source` grid must fit entirely inside this grid; if it doesn't,
+    /// nothing is copied (the source is *not* clipped), and the bounds error
+    /// of the out-of-bounds corner is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_fill((Rows(4), Columns(4)), &'.').unwrap();
+    /// let stamp = VecGrid::new_from_rows_owned(vec![
+    ///     vec!['a', 'b'],
+    ///     vec!['c', 'd'],
+    /// ]).unwrap();
+    ///
+    /// grid.paste((1, 2), &stamp).unwrap();
+    ///
+    /// assert_eq!(grid[(1, 2)], 'a');
+    /// assert_eq!(grid[(1, 3)], 'b');
+    /// assert_eq!(grid[(2, 2)], 'c');
+    /// assert_eq!(grid[(2, 3)], 'd');
+    /// assert_eq!(grid[(1, 1)], '.');
+    /// assert_eq!(grid[(3, 2)], '.');
+    ///
+    /// // The stamp doesn't fit here, so nothing is pasted
+    /// assert!(grid.paste((3, 0), &stamp).is_err());
+    /// assert_eq!(grid[(3, 0)], '.');
+    /// ```
+    pub fn paste(&mut self, at: impl LocationLike, source: &VecGrid<T>) -> Result<(), BoundsError> {
+        let at = at.as_location();
+        self.check_region(at, source.dimensions)?;
+
+        let width = source.dimensions.columns.0 as usize;
+
+        source
+            .storage
+            .chunks(width.max(1))
+            .zip(at.row.span(source.dimensions.rows))
+            .for_each(|(source_row, row)| {
+                // Safety: the region was bounds checked above
+                let start = unsafe { self.index_for_location(row + at.column) };
+                self.storage[start..start + width].clone_from_slice(source_row);
+            });
+
+        Ok(())
+    }
+
+    /// Fill a rectangular region of this grid, described by its `root` and
+    /// `dimensions`, with clones of `value`. This is the grid equivalent of
+    /// [`slice::fill`] over a sub-area, and is done a row at a time, which is
+    /// much faster than setting each cell individually.
+    ///
+    /// Like [`paste`][VecGrid::paste], the region must fit entirely inside
+    /// this grid; if it doesn't, nothing is filled (the region is *not*
+    /// clipped), and the bounds error of the out-of-bounds corner is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_fill((Rows(4), Columns(5)), &'.').unwrap();
+    ///
+    /// grid.fill_region((1, 1), (Rows(2), Columns(3)), &'#').unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.display_with(|c| *c).to_string(),
+    ///     ".....\n.###.\n.###.\n.....\n",
+    /// );
+    ///
+    /// // The region doesn't fit here, so nothing is filled
+    /// assert!(grid.fill_region((3, 3), (Rows(2), Columns(2)), &'x').is_err());
+    /// assert_eq!(grid[(3, 3)], '.');
+    /// ```
+    pub fn fill_region(
+        &mut self,
+        root: impl LocationLike,
+        dimensions: impl VectorLike,
+        value: &T,
+    ) -> Result<(), BoundsError> {
+        let root = root.as_location();
+        let dimensions = dimensions.as_vector();
+        self.check_region(root, dimensions)?;
+
+        if dimensions.columns <= 0 {
+            return Ok(());
+        }
+
+        let width = dimensions.columns.0 as usize;
+
+        root.row.span(dimensions.rows).for_each(|row| {
+            // Safety: the region was bounds checked above
+            let start = unsafe { self.index_for_location(row + root.column) };
+            self.storage[start..start + width].fill(value.clone());
+        });
+
+        Ok(())
+    }
+
+    /// Rotate a rectangular region of this grid in place, described by its
+    /// `root` and `size`. The region is rotated about its own center, so it
+    /// occupies the same cells afterwards. 90° rotations require the region
+    /// to be square; a [`Flip`][Rotation::Flip] can be applied to any region.
+    ///
+    /// Like [`paste`][VecGrid::paste], the region must fit entirely inside
+    /// this grid. If it doesn't, or if it needs to be square and isn't, the
+    /// grid is left unchanged and an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let mut grid = VecGrid::new_from_rows_owned(vec![
+    ///     "ab..".chars(),
+    ///     "cd..".chars(),
+    ///     "....".chars(),
+    /// ]).unwrap();
+    ///
+    /// grid.rotate_region((0, 0), (Rows(2), Columns(2)), Clockwise).unwrap();
+    /// assert_eq!(grid.display_with(|c| *c).to_string(), "ca..\ndb..\n....\n");
+    ///
+    /// grid.rotate_region((0, 0), (Rows(2), Columns(4)), Rotation::Flip).unwrap();
+    /// assert_eq!(grid.display_with(|c| *c).to_string(), "..bd\n..ac\n....\n");
+    ///
+    /// assert!(grid.rotate_region((0, 0), (Rows(2), Columns(4)), Anticlockwise).is_err());
+    /// assert!(grid.rotate_region((2, 2), (Rows(2), Columns(2)), Clockwise).is_err());
+    /// assert_eq!(grid.display_with(|c| *c).to_string(), "..bd\n..ac\n....\n");
+    /// ```
+    pub fn rotate_region(
+        &mut self,
+        root: impl LocationLike,
+        size: impl VectorLike,
+        rotation: Rotation,
+    ) -> Result<(), RotateRegionError> {
+        let root = root.as_location();
+        let size = size.as_vector();
+        self.check_region(root, size)?;
+
+        match rotation {
+            Rotation::None => return Ok(()),
+            Rotation::Flip => {}
+            Rotation::Clockwise | Rotation::Anticlockwise => {
+                if size.rows.0 != size.columns.0 {
+                    return Err(NotSquareError { dimensions: size }.into());
+                }
+            }
+        }
+
+        if size.rows <= 0 || size.columns <= 0 {
+            return Ok(());
+        }
+
+        let height = size.rows.0 as usize;
+        let width = size.columns.0 as usize;
+
+        let region: Vec<T> = root
+            .row
+            .span(size.rows)
+            .flat_map(|row| {
+                // Safety: the region was bounds checked above
+                let start = unsafe { self.index_for_location(row + root.column) };
+                self.storage[start..start + width].iter().cloned()
+            })
+            .collect();
+
+        for (row_offset, row) in root.row.span(size.rows).enumerate() {
+            // Safety: the region was bounds checked above
+            let start = unsafe { self.index_for_location(row + root.column) };
+
+            for column_offset in 0..width {
+                let (source_row, source_column) = match rotation {
+                    Rotation::Clockwise => (width - 1 - column_offset, row_offset),
+                    Rotation::Anticlockwise => (column_offset, height - 1 - row_offset),
+                    _ => (height - 1 - row_offset, width - 1 - column_offset),
+                };
+
+                self.storage[start + column_offset] =
+                    region[source_row * width + source_column].clone();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Clone + Eq + Hash> VecGrid<T> {
+    /// Get each distinct row in the grid, paired with the number of times it
+    /// appears, in the order that each row first appears. This is useful for
+    /// finding repeated structure in a grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridly_grids::VecGrid;
+    /// use gridly::prelude::*;
+    ///
+    /// let grid = VecGrid::new_from_rows_owned(vec![
+    ///     "#.#".chars(),
+    ///     "...".chars(),
+    ///     "#.#".chars(),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(grid.unique_rows(), [
+    ///     (vec!['#', '.', '#'], 2),
+    ///     (vec!['.', '.', '.'], 1),
+    /// ]);
+    /// ```
+    pub fn unique_rows(&self) -> Vec<(Vec<T>, usize)> {
+        let mut indexes: HashMap<&[T], usize> = HashMap::new();
+        let mut rows: Vec<(Vec<T>, usize)> = Vec::new();
+
+        for (_, cells) in self.scanline_slices() {
+            match indexes.entry(cells) {
+                Entry::Occupied(entry) => rows[*entry.get()].1 += 1,
+                Entry::Vacant(entry) => {
+                    entry.insert(rows.len());
+                    rows.push((cells.to_vec(), 1));
+                }
+            }
+        }
+
+        rows
+    }
 }
 
 impl<T: Copy> VecGrid<T> {
@@ -505,6 +1494,127 @@ impl<T: Copy> VecGrid<T> {
     }
 }
 
+/// The default `VecGrid` is an empty grid, with dimensions `(0, 0)`. Because
+/// it has no cells, it doesn't require `T: Default`. Every location is out of
+/// bounds for an empty grid, so it needs to be replaced with a larger grid
+/// before anything can be stored in it.
+///
+/// # Example
+///
+/// ```
+/// use gridly_grids::VecGrid;
+/// use gridly::prelude::*;
+///
+/// #[derive(Default)]
+/// struct Board {
+///     cells: VecGrid<i32>,
+/// }
+///
+/// let mut board = Board::default();
+///
+/// assert_eq!(board.cells.dimensions(), (0, 0));
+/// assert!(board.cells.set((0, 0), 1).is_err());
+///
+/// board.cells = VecGrid::new((Rows(2), Columns(2))).unwrap();
+/// assert!(board.cells.set((0, 0), 1).is_ok());
+/// assert_eq!(board.cells[(0, 0)], 1);
+/// ```
+impl<T> Default for VecGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        VecGrid {
+            dimensions: Vector::zero(),
+            storage: Vec::new(),
+        }
+    }
+}
+
+/// Approximate equality for grids of floating point numbers. Two grids are
+/// approximately equal if they have the same dimensions, and each pair of
+/// corresponding cells is approximately equal.
+///
+/// Requires the `approx` feature.
+///
+/// # Example
+///
+/// ```
+/// use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// use gridly_grids::VecGrid;
+/// use gridly::prelude::*;
+///
+/// let grid1 = VecGrid::new_row_major((Rows(2), Columns(2)), vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+/// let grid2 = VecGrid::new_row_major((Rows(2), Columns(2)), vec![0.1, 0.2, 0.3, 0.41]).unwrap();
+///
+/// assert_abs_diff_eq!(grid1, grid2, epsilon = 0.1);
+/// assert_abs_diff_ne!(grid1, grid2, epsilon = 0.001);
+///
+/// let grid3 = VecGrid::new_row_major((Rows(1), Columns(4)), vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+/// assert_abs_diff_ne!(grid1, grid3, epsilon = 0.1);
+/// ```
+#[cfg(feature = "approx")]
+impl<T: AbsDiffEq> AbsDiffEq for VecGrid<T>
+where
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.dimensions == other.dimensions
+            && self
+                .storage
+                .iter()
+                .zip(&other.storage)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+/// Relative approximate equality for grids of floating point numbers. Two
+/// grids are approximately equal if they have the same dimensions, and each
+/// pair of corresponding cells is approximately equal.
+///
+/// Requires the `approx` feature.
+///
+/// # Example
+///
+/// ```
+/// use approx::{assert_relative_eq, assert_relative_ne};
+/// use gridly_grids::VecGrid;
+/// use gridly::prelude::*;
+///
+/// let grid1 = VecGrid::new_row_major((Rows(1), Columns(2)), vec![1000.0, 1.0]).unwrap();
+/// let grid2 = VecGrid::new_row_major((Rows(1), Columns(2)), vec![1001.0, 1.0]).unwrap();
+///
+/// assert_relative_eq!(grid1, grid2, max_relative = 0.01);
+/// assert_relative_ne!(grid1, grid2, max_relative = 0.0001);
+/// ```
+#[cfg(feature = "approx")]
+impl<T: RelativeEq> RelativeEq for VecGrid<T>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.dimensions == other.dimensions
+            && self
+                .storage
+                .iter()
+                .zip(&other.storage)
+                .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
 impl<T> GridBounds for VecGrid<T> {
     #[inline]
     fn dimensions(&self) -> Vector {
@@ -562,3 +1672,95 @@ impl<T> GridMut for VecGrid<T> {
         self.storage.get_unchecked_mut(index)
     }
 }
+
+/// An iterator over the cells of a [`VecGrid`], paired with their locations,
+/// in row-major order. See [`VecGrid::iter_with_locations`] for details.
+#[derive(Debug)]
+pub struct VecGridCells<'a, T> {
+    locations: CrossRange<Row>,
+    cells: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for VecGridCells<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            locations: self.locations.clone(),
+            cells: self.cells.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for VecGridCells<'a, T> {
+    type Item = (Location, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.cells.next()?;
+        let location = self
+            .locations
+            .next()
+            .expect("VecGrid storage and locations are the same length");
+
+        Some((location, cell))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for VecGridCells<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let cell = self.cells.next_back()?;
+        let location = self
+            .locations
+            .next_back()
+            .expect("VecGrid storage and locations are the same length");
+
+        Some((location, cell))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VecGridCells<'a, T> {}
+impl<'a, T> FusedIterator for VecGridCells<'a, T> {}
+
+/// A mutable handle to a single row of a [`VecGrid`]. Dereferences to a
+/// mutable slice of the cells in the row. See [`VecGrid::row_entry`] for
+/// details.
+#[derive(Debug)]
+pub struct RowEntry<'a, T> {
+    row: Row,
+    cells: &'a mut [T],
+}
+
+impl<'a, T> RowEntry<'a, T> {
+    /// Get the index of this row
+    #[inline]
+    pub fn row(&self) -> Row {
+        self.row
+    }
+
+    /// Convert this entry into the mutable slice of the row's cells
+    #[inline]
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.cells
+    }
+}
+
+impl<'a, T> Deref for RowEntry<'a, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.cells
+    }
+}
+
+impl<'a, T> DerefMut for RowEntry<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.cells
+    }
+}